@@ -3,20 +3,41 @@
 import React, { useState, useEffect } from "react";
 import { motion } from "framer-motion";
 import { useAnchorWallet, useConnection } from "@solana/wallet-adapter-react";
-import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { PublicKey, SystemProgram, SYSVAR_SLOT_HASHES_PUBKEY } from "@solana/web3.js";
 import * as rawIdl from "../../idl.json";
 import type { DecentralizedAgSupply } from "../../types/decentralized_ag_supply";
 import Link from "next/link";
 import { ChevronLeft, AlertTriangle, Scale, CheckCircle } from "lucide-react";
 import { AnchorProvider, Program, setProvider } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddress, NATIVE_MINT } from "@solana/spl-token";
 import { toast } from "react-hot-toast";
 
 const programId = new PublicKey(rawIdl.address);
 
+const DISPUTE_REASONS = [
+  "quality",
+  "spoilage",
+  "coldChain",
+  "quantity",
+  "lateDelivery",
+  "nonDelivery",
+  "payment",
+  "other",
+] as const;
+type DisputeReason = (typeof DISPUTE_REASONS)[number];
+
+// Disputes are numbered per batch; the index is a u32 seed.
+const disputeIndexBuffer = (index: number) => {
+  const buffer = Buffer.alloc(4);
+  buffer.writeUInt32LE(index);
+  return buffer;
+};
+
 const ManageDisputesPage = () => {
   const { connection } = useConnection();
   const anchorWallet = useAnchorWallet();
   const [raiseProduceId, setRaiseProduceId] = useState("");
+  const [reason, setReason] = useState<DisputeReason>("quality");
   const [description, setDescription] = useState("");
   const [resolveProduceId, setResolveProduceId] = useState("");
   const [resolution, setResolution] = useState<boolean | null>(null);
@@ -90,20 +111,74 @@ const ManageDisputesPage = () => {
         [Buffer.from("produce"), produceIdBuffer],
         programId
       );
+      const produce = await program.account.produce.fetch(producePDA);
+      const mint = produce.settlementMint;
+
       const [disputePDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("dispute"), producePDA.toBuffer()],
+        [Buffer.from("dispute"), producePDA.toBuffer(), disputeIndexBuffer(produce.disputeCount)],
         programId
       );
-
-      await program.methods
-        .raiseDispute(description)
-        .accounts({
-          produce: producePDA,
-          raiser: anchorWallet.publicKey,
-          dispute: disputePDA,
-          systemProgram: SystemProgram.programId,
-        })
-        .rpc();
+      const [disputeConfigPDA] = PublicKey.findProgramAddressSync([Buffer.from("dispute_config")], programId);
+      const [raiserReputationPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reputation"), anchorWallet.publicKey.toBuffer()],
+        programId
+      );
+      const [treasuryPDA] = PublicKey.findProgramAddressSync([Buffer.from("treasury")], programId);
+
+      if (mint.equals(NATIVE_MINT)) {
+        // Native SOL batches lock the bond in the batch's SOL escrow.
+        const [solEscrowPDA] = PublicKey.findProgramAddressSync(
+          [Buffer.from("sol_escrow"), producePDA.toBuffer()],
+          programId
+        );
+        await program.methods
+          .raiseDisputeSol({ [reason]: {} } as any, description)
+          .accountsPartial({
+            produce: producePDA,
+            raiser: anchorWallet.publicKey,
+            dispute: disputePDA,
+            disputeConfig: disputeConfigPDA,
+            raiserReputation: raiserReputationPDA,
+            solEscrow: solEscrowPDA,
+            treasury: treasuryPDA,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      } else {
+        const mintInfo = await connection.getAccountInfo(mint);
+        if (!mintInfo) {
+          throw new Error("Settlement mint not found");
+        }
+        const tokenProgram = mintInfo.owner;
+        const [escrowPDA] = PublicKey.findProgramAddressSync(
+          [Buffer.from("escrow"), producePDA.toBuffer(), mint.toBuffer()],
+          programId
+        );
+        const [treasuryVaultPDA] = PublicKey.findProgramAddressSync(
+          [Buffer.from("treasury_vault"), mint.toBuffer()],
+          programId
+        );
+        // The dispute bond and its fee are paid from the raiser's token account.
+        const raiserTokenAccount = await getAssociatedTokenAddress(mint, anchorWallet.publicKey, false, tokenProgram);
+
+        await program.methods
+          .raiseDispute({ [reason]: {} } as any, description)
+          .accountsPartial({
+            produce: producePDA,
+            raiser: anchorWallet.publicKey,
+            dispute: disputePDA,
+            disputeConfig: disputeConfigPDA,
+            raiserReputation: raiserReputationPDA,
+            raiserTokenAccount,
+            escrow: escrowPDA,
+            mint,
+            treasury: treasuryPDA,
+            treasuryVault: treasuryVaultPDA,
+            tokenProgram,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      }
 
       const timestamp = new Date().toLocaleString();
       setRaiseResult({ produceId: raiseProduceId, timestamp });
@@ -148,21 +223,61 @@ const ManageDisputesPage = () => {
         [Buffer.from("produce"), produceIdBuffer],
         programId
       );
+      const produce = await program.account.produce.fetch(producePDA);
+      if (produce.disputeCount === 0) {
+        throw new Error("No dispute has been raised on this produce");
+      }
+      // Rules on the most recent dispute of the batch.
       const [disputePDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("dispute"), producePDA.toBuffer()],
+        [Buffer.from("dispute"), producePDA.toBuffer(), disputeIndexBuffer(produce.disputeCount - 1)],
         programId
       );
+      const [disputeConfigPDA] = PublicKey.findProgramAddressSync([Buffer.from("dispute_config")], programId);
       const [arbitratorPDA] = PublicKey.findProgramAddressSync(
         [Buffer.from("participant"), anchorWallet.publicKey.toBuffer()],
         programId
       );
+      const [registryPDA] = PublicKey.findProgramAddressSync([Buffer.from("arbitrator_registry")], programId);
+
+      // Only the arbitrators drawn for the dispute may rule; draw them first if
+      // nobody has yet.
+      let dispute = await program.account.dispute.fetch(disputePDA);
+      if (dispute.panel.length === 0) {
+        await program.methods
+          .assignPanel()
+          .accountsPartial({
+            dispute: disputePDA,
+            produce: producePDA,
+            disputeConfig: disputeConfigPDA,
+            registry: registryPDA,
+            slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          })
+          .rpc();
+        dispute = await program.account.dispute.fetch(disputePDA);
+      }
+      if (!dispute.panel.some((member) => member.equals(anchorWallet.publicKey))) {
+        throw new Error("You are not assigned to this dispute");
+      }
+
+      // Upholding the original terms pays farmer and transporter pro rata to
+      // their agreed amounts; upholding the dispute refunds the buyer.
+      const farmerPrice = Number(produce.farmerPrice);
+      const transporterFee = Number(produce.transporterFee);
+      const farmerBps = farmerPrice + transporterFee > 0
+        ? Math.round((farmerPrice * 10000) / (farmerPrice + transporterFee))
+        : 10000;
+      const split = resolution
+        ? { farmerBps, transporterBps: 10000 - farmerBps, buyerBps: 0 }
+        : { farmerBps: 0, transporterBps: 0, buyerBps: 10000 };
 
       await program.methods
-        .resolveDispute(resolution)
-        .accounts({
+        .resolveDispute(resolution, split)
+        .accountsPartial({
           dispute: disputePDA,
           produce: producePDA,
+          disputeConfig: disputeConfigPDA,
           arbitratorAccount: arbitratorPDA,
+          registry: registryPDA,
           arbitrator: anchorWallet.publicKey,
         })
         .rpc();
@@ -252,6 +367,29 @@ const ManageDisputesPage = () => {
                   />
                 </motion.div>
 
+                <motion.div
+                  initial={{ opacity: 0, y: 10 }}
+                  animate={{ opacity: 1, y: 0 }}
+                  transition={{ delay: 0.65, duration: 0.5 }}
+                >
+                  <label htmlFor="reason" className="block text-sm font-medium text-gray-700">
+                    Reason
+                  </label>
+                  <select
+                    id="reason"
+                    value={reason}
+                    onChange={(e) => setReason(e.target.value as DisputeReason)}
+                    className="mt-1 block w-full bg-white border border-gray-300 rounded-lg p-3 text-gray-700 focus:ring-2 focus:ring-green-500"
+                    disabled={isRaising}
+                  >
+                    {DISPUTE_REASONS.map((value) => (
+                      <option key={value} value={value}>
+                        {value.replace(/([A-Z])/g, " $1").replace(/^./, (c) => c.toUpperCase())}
+                      </option>
+                    ))}
+                  </select>
+                </motion.div>
+
                 <motion.div
                   initial={{ opacity: 0, y: 10 }}
                   animate={{ opacity: 1, y: 0 }}
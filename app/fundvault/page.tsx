@@ -22,24 +22,30 @@ import type { DecentralizedAgSupply } from "../../types/decentralized_ag_supply"
 import {
   PublicKey,
   SystemProgram,
-  Transaction,
   LAMPORTS_PER_SOL,
 } from "@solana/web3.js";
-import {
-  getAccount,
-  createAssociatedTokenAccountInstruction,
-  getAssociatedTokenAddress,
-  TOKEN_PROGRAM_ID,
-  createSyncNativeInstruction,
-  NATIVE_MINT,
-} from "@solana/spl-token";
 
 const programId = new PublicKey(rawIdl.address);
-const WSOL_MINT = NATIVE_MINT; // Wrapped SOL mint: So11111111111111111111111111111111111111112
 
 type ParticipantAccount = IdlAccounts<DecentralizedAgSupply>["participant"];
 type ProduceAccount = IdlAccounts<DecentralizedAgSupply>["produce"];
 
+// Mirrors the on-chain check in `fund_vault_sol`: the escrow must cover the
+// largest payouts a batch can reach (quality bonuses included), the
+// treasury's settlement fee on them and the fees of a full set of
+// inspections. SOL transfers carry no transfer fee to gross up.
+const MAX_INSPECTIONS = 8;
+
+const requiredEscrow = (produce: ProduceAccount, feeBps: number, inspectionFee: number) => {
+  const minReward = 10;
+  const farmerPrice = Number(produce.farmerPrice);
+  const transporterFee = Number(produce.transporterFee);
+  const farmerMax = Math.max(farmerPrice + Math.floor(farmerPrice / 5), minReward);
+  const transporterMax = Math.max(transporterFee + Math.floor(transporterFee / 10), minReward);
+  const payoutsMax = farmerMax + transporterMax;
+  return payoutsMax + Math.floor((payoutsMax * feeBps) / 10000) + MAX_INSPECTIONS * inspectionFee;
+};
+
 const FundVaultPage = () => {
   const { publicKey, wallet } = useWallet();
   const router = useRouter();
@@ -51,7 +57,7 @@ const FundVaultPage = () => {
 
   const [formData, setFormData] = useState({
     produceId: "",
-    amountToFund: 0, // In WSOL lamports
+    amountToFund: 0, // In lamports
   });
 
   const pageVariants = {
@@ -144,9 +150,24 @@ const FundVaultPage = () => {
       const produceAccount = (await (program.account as any).produce.fetch(
         producePDA
       )) as ProduceAccount;
-      const totalAmount =
-        Number(produceAccount.farmerPrice) +
-        Number(produceAccount.transporterFee);
+      const [treasuryPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("treasury")],
+        programId
+      );
+      const treasury = await program.account.treasury.fetch(treasuryPDA);
+      const [qualityConfigPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("quality_config")],
+        programId
+      );
+      const qualityConfig = await program.account.qualityConfig.fetch(qualityConfigPDA);
+      // The fee rates are fixed by the first deposit of a batch.
+      const funded = !produceAccount.buyer.equals(PublicKey.default);
+      const feeBps = funded ? produceAccount.protocolFeeBps : treasury.settlementFeeBps;
+      const inspectionFee = Number(funded ? produceAccount.inspectionFee : qualityConfig.inspectionFee);
+      const totalAmount = Math.max(
+        requiredEscrow(produceAccount, feeBps, inspectionFee) - Number(produceAccount.escrowedAmount),
+        0
+      );
       setFormData((prev) => ({
         ...prev,
         amountToFund: totalAmount,
@@ -154,7 +175,7 @@ const FundVaultPage = () => {
       console.log("Farmer Price:", Number(produceAccount.farmerPrice));
       console.log("Transporter Fee:", Number(produceAccount.transporterFee));
       console.log("Total Amount (lamports):", totalAmount);
-      toast.success(`Required amount: ${totalAmount / LAMPORTS_PER_SOL} WSOL`);
+      toast.success(`Required amount: ${totalAmount / LAMPORTS_PER_SOL} SOL`);
     } catch (err) {
       console.error("Error fetching produce details:", err);
       toast.error("Failed to fetch produce details. Invalid Produce ID?");
@@ -204,124 +225,56 @@ const FundVaultPage = () => {
         programId
       );
 
-      const [paymentVaultPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vault_token")],
+      const [mintAllowlistPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("mint_allowlist")],
         programId
       );
 
-      const retailerTokenAccount = await getAssociatedTokenAddress(
-        WSOL_MINT,
-        anchorWallet.publicKey,
-        false
+      const [treasuryPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("treasury")],
+        programId
       );
 
-      console.log("Produce PDA:", producePDA.toBase58());
-      console.log("Payment Vault PDA:", paymentVaultPDA.toBase58());
-      console.log("Retailer Token Account:", retailerTokenAccount.toBase58());
-
-      let transaction = new Transaction();
-
-      // Check if payment vault is initialized
-      const paymentVaultInfo = await connection.getAccountInfo(paymentVaultPDA);
-      if (!paymentVaultInfo) {
-        toast.error("Payment vault not initialized. Please contact support.");
-        console.error("Payment vault account does not exist.");
-        return;
-      }
-      const initialVaultBalance = await getAccount(connection, paymentVaultPDA);
-      console.log(
-        "Payment Vault WSOL Balance Before:",
-        Number(initialVaultBalance.amount) / LAMPORTS_PER_SOL,
-        "WSOL"
+      const [qualityConfigPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("quality_config")],
+        programId
       );
 
-      // Check retailer's token account
-      const retailerTokenAccountInfo = await connection.getAccountInfo(
-        retailerTokenAccount
+      const [solEscrowPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("sol_escrow"), producePDA.toBuffer()],
+        programId
       );
-      if (!retailerTokenAccountInfo) {
-        transaction.add(
-          createAssociatedTokenAccountInstruction(
-            anchorWallet.publicKey,
-            retailerTokenAccount,
-            anchorWallet.publicKey,
-            WSOL_MINT
-          )
-        );
-      }
 
-      // Check WSOL balance
-      let wsolBalance = 0;
-      if (retailerTokenAccountInfo) {
-        const tokenAccount = await getAccount(connection, retailerTokenAccount);
-        wsolBalance = Number(tokenAccount.amount);
-      }
-      console.log("Retailer WSOL Balance Before:", wsolBalance / LAMPORTS_PER_SOL, "WSOL");
-
-      // Wrap SOL if needed
-      if (wsolBalance < formData.amountToFund) {
-        const solBalance = await connection.getBalance(anchorWallet.publicKey);
-        const requiredSol = formData.amountToFund + 1000000; // Extra for fees
-        if (solBalance < requiredSol) {
-          throw new Error(
-            `Insufficient SOL. Required: ${requiredSol / LAMPORTS_PER_SOL} SOL, Available: ${solBalance / LAMPORTS_PER_SOL} SOL`
-          );
-        }
+      console.log("Produce PDA:", producePDA.toBase58());
+      console.log("SOL Escrow PDA:", solEscrowPDA.toBase58());
 
-        transaction.add(
-          SystemProgram.transfer({
-            fromPubkey: anchorWallet.publicKey,
-            toPubkey: retailerTokenAccount,
-            lamports: formData.amountToFund,
-          }),
-          createSyncNativeInstruction(retailerTokenAccount)
+      const solBalance = await connection.getBalance(anchorWallet.publicKey);
+      const requiredSol = formData.amountToFund + 1000000; // Extra for fees and escrow rent
+      if (solBalance < requiredSol) {
+        throw new Error(
+          `Insufficient SOL. Required: ${requiredSol / LAMPORTS_PER_SOL} SOL, Available: ${solBalance / LAMPORTS_PER_SOL} SOL`
         );
       }
 
-      // Add fundVault instruction
-      const fundVaultIx = await program.methods
-        .fundVault(new BN(formData.amountToFund))
-        .accounts({
+      // Batches paid in SOL are escrowed as lamports, not wrapped SOL.
+      const txId = await program.methods
+        .fundVaultSol(new BN(formData.amountToFund))
+        .accountsPartial({
           produce: producePDA,
           retailer: anchorWallet.publicKey,
-          retailerTokenAccount: retailerTokenAccount,
-          paymentVault: paymentVaultPDA,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          mintAllowlist: mintAllowlistPDA,
+          treasury: treasuryPDA,
+          qualityConfig: qualityConfigPDA,
+          solEscrow: solEscrowPDA,
+          systemProgram: SystemProgram.programId,
         })
-        .instruction();
-
-      transaction.add(fundVaultIx);
-
-      const { blockhash, lastValidBlockHeight } = await connection.getLatestBlockhash();
-      transaction.recentBlockhash = blockhash;
-      transaction.feePayer = anchorWallet.publicKey;
-
-      const signedTx = await anchorWallet.signTransaction(transaction);
-      const txId = await connection.sendRawTransaction(signedTx.serialize());
+        .rpc();
 
       console.log("Transaction ID:", txId);
-      await connection.confirmTransaction({
-        signature: txId,
-        blockhash,
-        lastValidBlockHeight,
-      });
-
-      // Verify balances after funding
-      const updatedRetailerAccount = await getAccount(connection, retailerTokenAccount);
-      console.log(
-        "Retailer WSOL Balance After:",
-        Number(updatedRetailerAccount.amount) / LAMPORTS_PER_SOL,
-        "WSOL"
-      );
-
-      const updatedVaultBalance = await getAccount(connection, paymentVaultPDA);
-      console.log(
-        "Payment Vault WSOL Balance After:",
-        Number(updatedVaultBalance.amount) / LAMPORTS_PER_SOL,
-        "WSOL"
-      );
+      const escrowBalance = await connection.getBalance(solEscrowPDA);
+      console.log("SOL Escrow Balance After:", escrowBalance / LAMPORTS_PER_SOL, "SOL");
 
-      toast.success("Vault funded successfully with WSOL!");
+      toast.success("Escrow funded successfully with SOL!");
       setTimeout(() => router.push("/dashboard"), 2000);
     } catch (error) {
       console.error("Error funding vault:", error);
@@ -398,7 +351,7 @@ const FundVaultPage = () => {
                   Secure Payment Funding
                 </p>
                 <p className="text-sm text-blue-700">
-                  Enter a Produce ID to fund the vault with Wrapped SOL (WSOL).
+                  Enter a Produce ID to fund the batch escrow with SOL.
                   Your SOL will be automatically wrapped if needed.
                 </p>
               </div>
@@ -445,14 +398,14 @@ const FundVaultPage = () => {
                 </button>
               </div>
               <p className="text-xs text-gray-500 mt-1">
-                Enter the Produce ID to retrieve funding requirements in WSOL
+                Enter the Produce ID to retrieve funding requirements in SOL
               </p>
             </motion.div>
 
             <motion.div variants={fieldVariants}>
               <label className="block text-gray-700 font-medium mb-2">
                 <Wallet size={16} className="inline mr-2" />
-                Amount to Fund (WSOL)
+                Amount to Fund (SOL)
               </label>
               <input
                 type="number"
@@ -462,7 +415,7 @@ const FundVaultPage = () => {
                 placeholder="Fetch amount using Produce ID"
               />
               <p className="text-xs text-gray-500 mt-1">
-                Total amount required: Farmer Price + Transporter Fee (in WSOL)
+                Total amount required: maximum payouts including quality bonuses, plus the protocol fee (in SOL)
               </p>
             </motion.div>
 
@@ -484,7 +437,7 @@ const FundVaultPage = () => {
                 ) : (
                   <>
                     <Lock className="mr-2 h-5 w-5" />
-                    Fund Vault with WSOL
+                    Fund Escrow with SOL
                   </>
                 )}
               </button>
@@ -492,7 +445,7 @@ const FundVaultPage = () => {
 
             <motion.div variants={fieldVariants}>
               <p className="text-xs text-gray-500 italic">
-                Note: Your SOL will be automatically wrapped to WSOL if needed.
+                Note: Any escrow left after settlement is refunded to you.
                 Ensure you have enough SOL.
               </p>
             </motion.div>
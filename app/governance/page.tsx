@@ -18,9 +18,9 @@ type Proposal = {
   description: string;
   votesFor: number;
   votesAgainst: number;
-  executed: boolean;
+  votesAbstain: number;
+  status: string;
   createdAt: string;
-  voterCount: number; // Number of unique voters
 };
 
 const GovernancePage = () => {
@@ -53,14 +53,14 @@ const GovernancePage = () => {
       const program = new Program(rawIdl as unknown as DecentralizedAgSupply, provider);
 
       const proposalAccounts = await (program.account as any).governanceProposal.all();
-      const fetchedProposals = proposalAccounts.map((account: { account: { proposalId: { toString: () => any; }; description: any; votesFor: { toNumber: () => any; }; votesAgainst: { toNumber: () => any; }; executed: any; createdAt: { toNumber: () => number; }; voters: string | any[]; }; }) => ({
+      const fetchedProposals = proposalAccounts.map((account: { account: { proposalId: { toString: () => any; }; description: any; votesFor: { toNumber: () => any; }; votesAgainst: { toNumber: () => any; }; votesAbstain: { toNumber: () => any; }; status: object; createdAt: { toNumber: () => number; }; }; }) => ({
         proposalId: `PROP-${account.account.proposalId.toString()}`,
         description: account.account.description,
         votesFor: account.account.votesFor.toNumber(),
         votesAgainst: account.account.votesAgainst.toNumber(),
-        executed: account.account.executed,
+        votesAbstain: account.account.votesAbstain.toNumber(),
+        status: Object.keys(account.account.status)[0], // e.g. "active", "queued", "executed"
         createdAt: new Date(account.account.createdAt.toNumber() * 1000).toLocaleString(),
-        voterCount: account.account.voters.length, // Number of unique voters
       }));
       setProposals(fetchedProposals);
     } catch (err) {
@@ -82,25 +82,13 @@ const GovernancePage = () => {
       setProvider(provider);
       const program = new Program(rawIdl as unknown as DecentralizedAgSupply, provider);
 
-      let id = Math.floor(Math.random() * 1000000);
-      let exists = true;
-
-      while (exists) {
-        const proposalIdBN = new BN(id);
-        const [proposalPDA] = PublicKey.findProgramAddressSync(
-          [Buffer.from("proposal"), proposalIdBN.toArrayLike(Buffer, "le", 8)],
-          programId
-        );
-        const accountInfo = await connection.getAccountInfo(proposalPDA);
-        if (!accountInfo) {
-          exists = false;
-        } else {
-          id += 1;
-        }
-      }
+      // Proposal IDs are assigned in order by the governance config.
+      const [configPDA] = PublicKey.findProgramAddressSync([Buffer.from("governance_config")], programId);
+      const config = await program.account.governanceConfig.fetch(configPDA);
+      const id = config.proposalCount.toString();
 
       setProposalIdCreate(`PROP-${id}`);
-      toast.success(`Generated unique Proposal ID: PROP-${id}`);
+      toast.success(`Next Proposal ID: PROP-${id}`);
     } catch (err) {
       console.error("Error generating proposal ID:", err);
       toast.error("Failed to generate unique ID.");
@@ -135,16 +123,34 @@ const GovernancePage = () => {
       setProvider(provider);
       const program = new Program(rawIdl as unknown as DecentralizedAgSupply, provider);
 
+      const [configPDA] = PublicKey.findProgramAddressSync([Buffer.from("governance_config")], programId);
+      const config = await program.account.governanceConfig.fetch(configPDA);
+      if (!config.proposalCount.eq(new BN(proposalIdNum))) {
+        toast.error(`Proposal IDs are assigned in order; the next one is PROP-${config.proposalCount.toString()}`);
+        return;
+      }
+
       const proposalIdBN = new BN(proposalIdNum);
       const [proposalPDA] = PublicKey.findProgramAddressSync(
         [Buffer.from("proposal"), proposalIdBN.toArrayLike(Buffer, "le", 8)],
         programId
       );
+      const [proposerStakePDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), anchorWallet.publicKey.toBuffer()],
+        programId
+      );
+      const [proposerAccountPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("participant"), anchorWallet.publicKey.toBuffer()],
+        programId
+      );
 
       await program.methods
-        .createProposal(proposalIdBN, description)
-        .accounts({
+        .createProposal({ general: {} }, { none: {} }, description)
+        .accountsPartial({
+          config: configPDA,
           proposal: proposalPDA,
+          proposerStake: proposerStakePDA,
+          proposerAccount: proposerAccountPDA,
           proposer: anchorWallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -190,11 +196,27 @@ const GovernancePage = () => {
         programId
       );
 
+      const [configPDA] = PublicKey.findProgramAddressSync([Buffer.from("governance_config")], programId);
+      const [voterAccountPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("participant"), anchorWallet.publicKey.toBuffer()],
+        programId
+      );
+      const [registryPDA] = PublicKey.findProgramAddressSync([Buffer.from("arbitrator_registry")], programId);
+      const [voteRecordPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), proposalPDA.toBuffer(), anchorWallet.publicKey.toBuffer()],
+        programId
+      );
+
       await program.methods
-        .voteProposal(proposalIdBN, voteFor)
-        .accounts({
+        .voteProposal(proposalIdBN, voteFor ? { for: {} } : { against: {} })
+        .accountsPartial({
+          config: configPDA,
           proposal: proposalPDA,
+          voteRecord: voteRecordPDA,
+          voterAccount: voterAccountPDA,
+          registry: registryPDA,
           voter: anchorWallet.publicKey,
+          systemProgram: SystemProgram.programId,
         })
         .rpc();
 
@@ -546,8 +568,8 @@ const GovernancePage = () => {
                       <p><strong>Description:</strong> {proposal.description}</p>
                       <p><strong>Votes For:</strong> {proposal.votesFor}</p>
                       <p><strong>Votes Against:</strong> {proposal.votesAgainst}</p>
-                      <p><strong>Voters:</strong> {proposal.voterCount}</p>
-                      <p><strong>Status:</strong> {proposal.executed ? "Executed" : "Active"}</p>
+                      <p><strong>Votes Abstain:</strong> {proposal.votesAbstain}</p>
+                      <p><strong>Status:</strong> {proposal.status.charAt(0).toUpperCase() + proposal.status.slice(1)}</p>
                       <p><strong>Created:</strong> {proposal.createdAt}</p>
                     </motion.div>
                   ))}
@@ -357,7 +357,7 @@ const LogHarvest = () => {
           formData.produceType,
           new BN(formData.quantity),
           new BN(new Date(formData.harvestDate).getTime() / 1000),
-          new BN(formData.quality),
+          Number(formData.quality),
           formData.qrCodeUri,
           new BN(formData.farmerPrice), // Added: Farmer price
           new BN(formData.transporterFee) // Added: Transporter fee
@@ -179,7 +179,7 @@ const FarmerPickupConfirmation = () => {
 
       await program.methods
         .confirmPickup()
-        .accounts({
+        .accountsPartial({
           produce: producePDA,
           farmerAccount: farmerPDA,
           farmer: anchorWallet.publicKey,
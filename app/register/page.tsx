@@ -228,10 +228,23 @@ const Register = () => {
         default: roleEnum = { farmer: {} };
       }
 
+      const [reputationPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reputation"), anchorWallet.publicKey.toBuffer()],
+        programId
+      );
+
+      // Arbitrators must already be appointed in the registry.
+      const [registryPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("arbitrator_registry")],
+        programId
+      );
+
       await program.methods
         .registerParticipant(roleEnum, name, contact)
-        .accounts({
+        .accountsPartial({
           participant: participantPDA,
+          reputation: reputationPDA,
+          registry: registryPDA,
           user: anchorWallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -3,7 +3,7 @@
 import React, { useEffect, useState } from "react";
 import { motion } from "framer-motion";
 import { useAnchorWallet, useConnection } from "@solana/wallet-adapter-react";
-import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { PublicKey } from "@solana/web3.js";
 import { AnchorProvider, BN, Program } from "@coral-xyz/anchor";
 import * as rawIdl from "../../idl.json";
 import type { DecentralizedAgSupply } from "../../types/decentralized_ag_supply";
@@ -246,13 +246,18 @@ const TransporterDashboard = () => {
         programId
       );
 
+      const [transporterReputationPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reputation"), anchorWallet.publicKey.toBuffer()],
+        programId
+      );
+
       await program.methods
         .recordPickup(parseInt(temperature), parseInt(humidity))
-        .accounts({
+        .accountsPartial({
           produce: producePDA,
           transporterAccount: transporterPDA,
+          transporterReputation: transporterReputationPDA,
           transporter: anchorWallet.publicKey,
-          systemProgram: SystemProgram.programId,
         })
         .rpc();
 
@@ -305,11 +310,10 @@ const TransporterDashboard = () => {
 
       await program.methods
         .recordDelivery()
-        .accounts({
+        .accountsPartial({
           produce: producePDA,
           transporterAccount: transporterPDA,
           transporter: anchorWallet.publicKey,
-          systemProgram: SystemProgram.programId,
         })
         .rpc();
 
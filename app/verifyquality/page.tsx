@@ -94,11 +94,33 @@ const VerifyQualityPage = () => {
         programId
       );
 
+      const produce = await program.account.produce.fetch(producePDA);
+      const [inspectionPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("inspection"), producePDA.toBuffer(), anchorWallet.publicKey.toBuffer()],
+        programId
+      );
+      const [certificationPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("inspector_cert"), anchorWallet.publicKey.toBuffer()],
+        programId
+      );
+      // Certified inspectors pass their certification; others inspect without one.
+      const certification = (await connection.getAccountInfo(certificationPDA)) ? certificationPDA : null;
+      const [qualityConfigPDA] = PublicKey.findProgramAddressSync([Buffer.from("quality_config")], programId);
+      const [farmerReputationPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reputation"), produce.farmer.toBuffer()],
+        programId
+      );
+
+      // The form records a single score, used for every grade; no lab report is attached.
       await program.methods
-        .verifyQuality(quality)
-        .accounts({
+        .verifyQuality(quality, { size: quality, color: quality, defects: quality }, Array(32).fill(0))
+        .accountsPartial({
           produce: producePDA,
+          inspection: inspectionPDA,
           verifierAccount: verifierPDA,
+          certification,
+          qualityConfig: qualityConfigPDA,
+          farmerReputation: farmerReputationPDA,
           verifier: anchorWallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -0,0 +1,299 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::error::ErrorCode;
+use crate::dispute::Dispute;
+use crate::governance::{GovernanceConfig, GovernanceProposal, ProposalAction};
+use crate::payment::{transfer_from_vault, transfer_lamports, SolEscrow, Vault};
+use crate::produce::Produce;
+
+pub const MAX_ARBITRATORS: usize = 32;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ArbitratorStatus {
+    Active,
+    Suspended,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ArbitratorEntry {
+    pub arbitrator: Pubkey,
+    pub status: ArbitratorStatus,
+    pub case_count: u32,      // rulings submitted
+    pub appointed_at: i64,
+    pub majority_count: u32,  // rulings that matched the final outcome
+    pub dissent_count: u32,   // rulings outvoted by the rest of the panel
+    pub timeout_count: u32,   // assigned cases left without a ruling
+    pub fees_earned: u64,
+    pub fees_unclaimed: u64,  // held in batch escrows until claimed
+}
+
+impl ArbitratorEntry {
+    // Size: arbitrator (32) + status (1) + case_count (4) + appointed_at (8) + majority_count (4)
+    // + dissent_count (4) + timeout_count (4) + fees_earned (8) + fees_unclaimed (8) = 73.
+    pub const LEN: usize = 73;
+}
+
+/// Arbitrators appointed by the admin or by executed governance proposals.
+/// Only active entries may rule on disputes.
+#[account]
+pub struct ArbitratorRegistry {
+    pub arbitrators: Vec<ArbitratorEntry>,
+    pub bump: u8,
+}
+
+impl ArbitratorRegistry {
+    // Size: arbitrators (4 + 73 * MAX_ARBITRATORS) + bump (1) = 2341.
+    pub const LEN: usize = 4 + ArbitratorEntry::LEN * MAX_ARBITRATORS + 1;
+
+    pub fn find(&self, arbitrator: &Pubkey) -> Option<&ArbitratorEntry> {
+        self.arbitrators.iter().find(|entry| entry.arbitrator == *arbitrator)
+    }
+
+    pub fn find_mut(&mut self, arbitrator: &Pubkey) -> Option<&mut ArbitratorEntry> {
+        self.arbitrators.iter_mut().find(|entry| entry.arbitrator == *arbitrator)
+    }
+
+    pub fn is_active(&self, arbitrator: &Pubkey) -> bool {
+        self.find(arbitrator)
+            .is_some_and(|entry| entry.status == ArbitratorStatus::Active)
+    }
+
+    /// Returns the registry entry of an active arbitrator, or an error otherwise.
+    pub fn require_active(&mut self, arbitrator: &Pubkey) -> Result<&mut ArbitratorEntry> {
+        match self.find_mut(arbitrator) {
+            Some(entry) if entry.status == ArbitratorStatus::Active => Ok(entry),
+            _ => Err(ErrorCode::ArbitratorNotActive.into()),
+        }
+    }
+
+    /// Adds an arbitrator, or reactivates a suspended one.
+    fn appoint(&mut self, arbitrator: Pubkey, now: i64) -> Result<()> {
+        if let Some(entry) = self.find_mut(&arbitrator) {
+            entry.status = ArbitratorStatus::Active;
+            return Ok(());
+        }
+        require!(self.arbitrators.len() < MAX_ARBITRATORS, ErrorCode::RegistryFull);
+        self.arbitrators.push(ArbitratorEntry {
+            arbitrator,
+            status: ArbitratorStatus::Active,
+            case_count: 0,
+            appointed_at: now,
+            majority_count: 0,
+            dissent_count: 0,
+            timeout_count: 0,
+            fees_earned: 0,
+            fees_unclaimed: 0,
+        });
+        Ok(())
+    }
+
+    fn suspend(&mut self, arbitrator: &Pubkey) -> Result<()> {
+        let entry = self.find_mut(arbitrator).ok_or(ErrorCode::ArbitratorNotActive)?;
+        entry.status = ArbitratorStatus::Suspended;
+        Ok(())
+    }
+}
+
+#[event]
+pub struct ArbitrationFeesClaimed {
+    pub arbitrator: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ArbitratorStatusChanged {
+    pub arbitrator: Pubkey,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+/// Creates the arbitrator registry. Governance admin only.
+pub fn initialize_arbitrator_registry(ctx: Context<InitializeArbitratorRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    registry.arbitrators = Vec::new();
+    registry.bump = ctx.bumps.registry;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeArbitratorRegistry<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ArbitratorRegistry::LEN,
+        seeds = [b"arbitrator_registry"],
+        bump
+    )]
+    pub registry: Account<'info, ArbitratorRegistry>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Appoints (or reinstates) an arbitrator. Governance admin only.
+pub fn appoint_arbitrator(ctx: Context<ManageArbitrator>, arbitrator: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.registry.appoint(arbitrator, now)?;
+    emit!(ArbitratorStatusChanged {
+        arbitrator,
+        active: true,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Suspends an arbitrator. Governance admin only.
+pub fn suspend_arbitrator(ctx: Context<ManageArbitrator>, arbitrator: Pubkey) -> Result<()> {
+    ctx.accounts.registry.suspend(&arbitrator)?;
+    emit!(ArbitratorStatusChanged {
+        arbitrator,
+        active: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ManageArbitrator<'info> {
+    #[account(mut, seeds = [b"arbitrator_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ArbitratorRegistry>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    pub admin: Signer<'info>,
+}
+
+/// Applies a queued arbitrator appointment or suspension proposal once its
+/// timelock has elapsed. Anyone may call this.
+pub fn execute_arbitrator_proposal(ctx: Context<ExecuteArbitratorProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let registry = &mut ctx.accounts.registry;
+    let now = Clock::get()?.unix_timestamp;
+    let (arbitrator, active) = match proposal.action {
+        ProposalAction::AppointArbitrator { arbitrator } => {
+            registry.appoint(arbitrator, now)?;
+            (arbitrator, true)
+        }
+        ProposalAction::SuspendArbitrator { arbitrator } => {
+            registry.suspend(&arbitrator)?;
+            (arbitrator, false)
+        }
+        _ => return Err(ErrorCode::ProposalActionMismatch.into()),
+    };
+    proposal.begin_execution()?;
+
+    emit!(ArbitratorStatusChanged {
+        arbitrator,
+        active,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteArbitratorProposal<'info> {
+    #[account(mut, seeds = [b"proposal", &proposal.proposal_id.to_le_bytes()], bump)]
+    pub proposal: Account<'info, GovernanceProposal>,
+    #[account(mut, seeds = [b"arbitrator_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ArbitratorRegistry>,
+    pub executor: Signer<'info>,
+}
+
+/// Pays out an arbitrator's share of the fee collected on a settled dispute.
+pub fn claim_arbitration_fees(ctx: Context<ClaimArbitrationFees>) -> Result<()> {
+    let amount = take_fee_share(
+        &mut ctx.accounts.registry,
+        &mut ctx.accounts.dispute,
+        ctx.accounts.arbitrator.key(),
+    )?;
+    let accounts = &ctx.accounts;
+    transfer_from_vault(
+        &accounts.token_program,
+        &accounts.escrow,
+        &accounts.arbitrator_token_account,
+        &accounts.mint,
+        &accounts.vault,
+        amount,
+    )
+}
+
+/// Native SOL counterpart of `claim_arbitration_fees`.
+pub fn claim_arbitration_fees_sol(ctx: Context<ClaimArbitrationFeesSol>) -> Result<()> {
+    let amount = take_fee_share(
+        &mut ctx.accounts.registry,
+        &mut ctx.accounts.dispute,
+        ctx.accounts.arbitrator.key(),
+    )?;
+    transfer_lamports(
+        &ctx.accounts.sol_escrow.to_account_info(),
+        &ctx.accounts.arbitrator.to_account_info(),
+        amount,
+    )
+}
+
+/// Records an arbitrator's claim on a settled dispute and returns their share.
+fn take_fee_share(registry: &mut ArbitratorRegistry, dispute: &mut Dispute, arbitrator: Pubkey) -> Result<u64> {
+    require!(dispute.settled, ErrorCode::InvalidStatus);
+    require!(!dispute.fee_claimants.contains(&arbitrator), ErrorCode::FeeAlreadyClaimed);
+    let amount = dispute.fee_share(&arbitrator).ok_or(ErrorCode::Unauthorized)?;
+    dispute.fee_claimants.push(arbitrator);
+
+    let entry = registry.find_mut(&arbitrator).ok_or(ErrorCode::ArbitratorNotActive)?;
+    entry.fees_unclaimed = entry.fees_unclaimed.saturating_sub(amount);
+
+    emit!(ArbitrationFeesClaimed {
+        arbitrator,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(amount)
+}
+
+#[derive(Accounts)]
+pub struct ClaimArbitrationFees<'info> {
+    #[account(mut, seeds = [b"arbitrator_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ArbitratorRegistry>,
+    #[account(mut, has_one = produce)]
+    pub dispute: Account<'info, Dispute>,
+    pub produce: Account<'info, Produce>,
+    pub arbitrator: Signer<'info>,
+    #[account(
+        mut,
+        constraint = arbitrator_token_account.owner == arbitrator.key() @ ErrorCode::InvalidPayoutAccount
+    )]
+    pub arbitrator_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"vault"], bump = vault.bump)]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"escrow", produce.key().as_ref(), produce.settlement_mint.as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = produce.settlement_mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimArbitrationFeesSol<'info> {
+    #[account(mut, seeds = [b"arbitrator_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ArbitratorRegistry>,
+    #[account(mut, has_one = produce)]
+    pub dispute: Account<'info, Dispute>,
+    pub produce: Account<'info, Produce>,
+    #[account(mut)]
+    pub arbitrator: Signer<'info>,
+    #[account(mut, seeds = [b"sol_escrow", produce.key().as_ref()], bump = sol_escrow.bump)]
+    pub sol_escrow: Account<'info, SolEscrow>,
+}
@@ -1,49 +1,620 @@
 use anchor_lang::prelude::*;
-use crate::produce::{Produce, ProduceStatus};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::produce::Produce;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use anchor_lang::system_program;
+use crate::arbitrator::{ArbitratorRegistry, ArbitratorStatus};
+use crate::governance::GovernanceConfig;
+use crate::invoice::*;
+use crate::payment::{
+    gross_up, settlement_breakdown, transfer_from_vault, transfer_lamports, Milestone, SolEscrow, Vault,
+};
+use crate::reputation::Reputation;
+use crate::treasury::{collect_fee, collect_sol_fee, protocol_fee, Treasury};
 use crate::error::ErrorCode;
 
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// How a batch's escrowed funds are divided when a dispute is resolved,
+/// in basis points of the escrowed amount. The shares must sum to 10,000.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct SettlementSplit {
+    pub farmer_bps: u16,
+    pub transporter_bps: u16,
+    pub buyer_bps: u16, // refunded to the buyer
+}
+
+impl SettlementSplit {
+    pub const LEN: usize = 6;
+
+    pub fn validate(&self) -> Result<()> {
+        let total = self.farmer_bps as u64 + self.transporter_bps as u64 + self.buyer_bps as u64;
+        require!(total == BPS_DENOMINATOR, ErrorCode::InvalidSplit);
+        Ok(())
+    }
+
+    /// Splits `amount` into (farmer, transporter, buyer). Rounding dust goes to the buyer.
+    pub fn apply(&self, amount: u64) -> Result<(u64, u64, u64)> {
+        let share = |bps: u16| -> Result<u64> {
+            let value = (amount as u128) * (bps as u128) / (BPS_DENOMINATOR as u128);
+            u64::try_from(value).map_err(|_| ErrorCode::Overflow.into())
+        };
+        let farmer = share(self.farmer_bps)?;
+        let transporter = share(self.transporter_bps)?;
+        let buyer = amount
+            .checked_sub(farmer)
+            .and_then(|rest| rest.checked_sub(transporter))
+            .ok_or(ErrorCode::Overflow)?;
+        Ok((farmer, transporter, buyer))
+    }
+}
+
+/// Category of the problem behind a dispute.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DisputeReason {
+    Quality,
+    Spoilage,
+    ColdChain,
+    Quantity,
+    LateDelivery,
+    NonDelivery,
+    Payment,
+    Other,
+}
+
+pub const MAX_PANEL_SIZE: usize = 5;
+pub const MAX_APPEALS: u8 = 1;
+
+/// Tunable dispute parameters.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DisputeParams {
+    pub bond_amount: u64,     // tokens each side locks; the loser's bond pays the arbitration fee and goes to the winner
+    pub evidence_period: i64, // seconds after raising during which evidence may be submitted
+    pub panel_threshold: u64, // escrowed amount from which a panel decides instead of one arbitrator
+    pub panel_size: u8,       // odd number of arbitrators on a panel
+    pub appeal_window: i64,   // seconds after a ruling during which the loser may appeal
+    pub appeal_bond: u64,     // must be at least the dispute bond
+    pub arbitration_fee: u64, // paid to the ruling arbitrators out of the loser's bond; at most bond_amount
+    pub max_ruling_time: i64, // seconds arbitrators have to rule once evidence closes
+}
+
+impl DisputeParams {
+    // Size: bond_amount (8) + evidence_period (8) + panel_threshold (8) + panel_size (1) + appeal_window (8) + appeal_bond (8)
+    // + arbitration_fee (8) + max_ruling_time (8) = 57.
+    pub const LEN: usize = 57;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(self.evidence_period >= 0, ErrorCode::InvalidConfig);
+        require!(self.appeal_window >= 0, ErrorCode::InvalidConfig);
+        require!(self.max_ruling_time > 0, ErrorCode::InvalidConfig);
+        require!(self.appeal_bond >= self.bond_amount, ErrorCode::InvalidConfig);
+        require!(self.arbitration_fee <= self.bond_amount, ErrorCode::InvalidConfig);
+        require!(
+            self.panel_size % 2 == 1 && self.panel_size as usize <= MAX_PANEL_SIZE,
+            ErrorCode::InvalidConfig
+        );
+        Ok(())
+    }
+}
+
+/// Dispute parameters set by the governance admin.
+#[account]
+pub struct DisputeConfig {
+    pub params: DisputeParams,
+    pub bump: u8,
+}
+
+impl DisputeConfig {
+    // Size: params (57) + bump (1) = 58.
+    pub const LEN: usize = DisputeParams::LEN + 1;
+}
+
+/// One panel member's ruling.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PanelRuling {
+    pub arbitrator: Pubkey,
+    pub resolution: bool,
+    pub split: SettlementSplit,
+}
+
+impl PanelRuling {
+    // Size: arbitrator (32) + resolution (1) + split (6) = 39.
+    pub const LEN: usize = 32 + 1 + SettlementSplit::LEN;
+}
+
 #[account]
 pub struct Dispute {
     pub produce: Pubkey,
+    pub index: u32, // position among the batch's disputes
     pub raiser: Pubkey,
     pub description: String, // (max ~128 characters)
     pub created_at: i64,
     pub resolved: bool,
     pub resolution: bool, // true if resolved in favor of the original terms
+    pub split: SettlementSplit,
+    pub reason: DisputeReason,
+    pub bond_amount: u64,
+    pub evidence_count: u32,
+    pub evidence_deadline: i64, // no evidence after this; arbitrators may rule from then on
+    pub panel_required: bool,   // large-value batch: decided by a panel, not a single arbitrator
+    pub panel: Vec<Pubkey>,     // assigned arbitrators; a single one unless panel_required (max MAX_PANEL_SIZE)
+    pub rulings: Vec<PanelRuling>,
+    pub dissenters: Vec<Pubkey>, // panel members who ruled against the majority
+    pub ruled_at: i64,
+    pub appeal_deadline: i64,    // funds stay frozen until this passes
+    pub settled: bool,           // escrow and bonds have been paid out
+    pub appeal_round: u8,
+    pub appellant: Pubkey,
+    pub appeal_bond: u64,
+    pub appealed_resolution: bool,   // the ruling that was appealed
+    pub prior_arbitrators: Vec<Pubkey>, // excluded from the appeal panel
+    pub arbitration_fee: u64,
+    pub ruling_due_at: i64,      // after this anyone may resolve the dispute by the default rule
+    pub timed_out: bool,
+    pub fee_collected: u64,      // arbitration fee actually collected at settlement
+    pub fee_claimants: Vec<Pubkey>, // arbitrators who have claimed their share
+    pub response_bond: u64,      // bond locked by the respondent when answering
+    pub escrow_share: u64,       // part of the batch escrow this dispute decides
 }
 
 impl Dispute {
-    // Size: produce (32) + raiser (32) + description (4+128) + created_at (8) + resolved (1) + resolution (1) = ~206, plus 8 = 214.
-    pub const LEN: usize = 214;
+    // Size: produce (32) + raiser (32) + description (4+128) + created_at (8) + resolved (1) + resolution (1) = 206
+    // + index (4) + split (6) + reason (1) + bond_amount (8) + evidence_count (4) + evidence_deadline (8)
+    // + panel_required (1) + panel (4+32*5) + rulings (4+39*5) + dissenters (4+32*5)
+    pub const LEN: usize = 206 + 4 + SettlementSplit::LEN + 1 + 8 + 4 + 8
+        + 1
+        + (4 + 32 * MAX_PANEL_SIZE)
+        + (4 + PanelRuling::LEN * MAX_PANEL_SIZE)
+        + (4 + 32 * MAX_PANEL_SIZE)
+        // + ruled_at (8) + appeal_deadline (8) + settled (1) + appeal_round (1) + appellant (32)
+        // + appeal_bond (8) + appealed_resolution (1) + prior_arbitrators (4+32*5)
+        + 8 + 8 + 1 + 1 + 32 + 8 + 1
+        + (4 + 32 * MAX_PANEL_SIZE)
+        // + arbitration_fee (8) + ruling_due_at (8) + timed_out (1)
+        + 8 + 8 + 1
+        // + fee_collected (8) + fee_claimants (4+32*5)
+        + 8 + (4 + 32 * MAX_PANEL_SIZE)
+        // + response_bond (8) + escrow_share (8)
+        + 8 + 8;
+
+    /// The party the dispute is raised against: the farmer, or the buyer when
+    /// the farmer raised the dispute.
+    pub fn respondent(&self, produce: &Produce) -> Pubkey {
+        if self.raiser == produce.farmer {
+            produce.buyer
+        } else {
+            produce.farmer
+        }
+    }
+
+    /// Who receives the bond: the raiser if the dispute is upheld, otherwise
+    /// the respondent.
+    pub fn bond_recipient(&self, produce: &Produce) -> Pubkey {
+        if !self.resolution {
+            self.raiser
+        } else {
+            self.respondent(produce)
+        }
+    }
+
+    /// Whether arbitrators decided the outcome: the dispute did not time out,
+    /// or its appeal did and the appealed ruling stands.
+    pub fn adjudicated(&self) -> bool {
+        !self.timed_out || self.appeal_round > 0
+    }
+
+    /// Who receives the response bond: the raiser if the dispute is upheld by
+    /// a ruling, otherwise the respondent. A dispute nobody ruled on returns it.
+    pub fn response_bond_recipient(&self, produce: &Produce) -> Pubkey {
+        if !self.resolution && self.adjudicated() {
+            self.raiser
+        } else {
+            self.respondent(produce)
+        }
+    }
+
+    /// The party whose reputation records the loss: the raiser when the
+    /// dispute was rejected, the respondent when it was upheld.
+    pub fn loser(&self, produce: &Produce) -> Pubkey {
+        if self.resolution {
+            self.raiser
+        } else {
+            self.respondent(produce)
+        }
+    }
+
+    /// Who receives the appeal bond: the appellant if the appeal overturned the
+    /// ruling or was never ruled on, otherwise the party that receives the
+    /// dispute bond.
+    pub fn appeal_bond_recipient(&self, produce: &Produce) -> Pubkey {
+        if self.timed_out || self.resolution != self.appealed_resolution {
+            self.appellant
+        } else {
+            self.bond_recipient(produce)
+        }
+    }
+
+    /// Whether `key` lost the current ruling: the raiser when the dispute was
+    /// rejected, any other party to the shipment when it was upheld.
+    pub fn is_losing_party(&self, produce: &Produce, key: &Pubkey) -> bool {
+        if self.resolution {
+            *key == self.raiser
+        } else {
+            *key != self.raiser && produce.is_party(key)
+        }
+    }
+
+    /// Arbitrators whose ruling matched the outcome, in ruling order.
+    pub fn winners(&self) -> Vec<Pubkey> {
+        self.rulings
+            .iter()
+            .filter(|ruling| ruling.resolution == self.resolution)
+            .map(|ruling| ruling.arbitrator)
+            .collect()
+    }
+
+    /// An arbitrator's share of the collected fee. Rounding dust goes to the
+    /// first arbitrator in the majority.
+    pub fn fee_share(&self, arbitrator: &Pubkey) -> Option<u64> {
+        let winners = self.winners();
+        let position = winners.iter().position(|winner| winner == arbitrator)?;
+        let count = winners.len() as u64;
+        let mut share = self.fee_collected / count;
+        if position == 0 {
+            share += self.fee_collected % count;
+        }
+        Some(share)
+    }
+
+    /// Returns the panel's outcome once a majority agrees, recording the
+    /// dissenting arbitrators. The applied split is the average of the
+    /// majority's splits, with rounding dust refunded to the buyer.
+    fn majority_ruling(&mut self) -> Option<(bool, SettlementSplit)> {
+        let needed = self.panel.len() / 2 + 1;
+        for outcome in [true, false] {
+            let majority: Vec<&PanelRuling> =
+                self.rulings.iter().filter(|ruling| ruling.resolution == outcome).collect();
+            if majority.len() < needed {
+                continue;
+            }
+            let count = majority.len() as u64;
+            let farmer_bps = majority.iter().map(|r| r.split.farmer_bps as u64).sum::<u64>() / count;
+            let transporter_bps = majority.iter().map(|r| r.split.transporter_bps as u64).sum::<u64>() / count;
+            let split = SettlementSplit {
+                farmer_bps: farmer_bps as u16,
+                transporter_bps: transporter_bps as u16,
+                buyer_bps: (BPS_DENOMINATOR - farmer_bps - transporter_bps) as u16,
+            };
+            self.dissenters = self
+                .rulings
+                .iter()
+                .filter(|ruling| ruling.resolution != outcome)
+                .map(|ruling| ruling.arbitrator)
+                .collect();
+            return Some((outcome, split));
+        }
+        None
+    }
+}
+
+/// A piece of evidence attached to a dispute. The content itself (photo, lab
+/// report) lives off-chain at `uri`; `content_hash` pins its exact contents.
+#[account]
+pub struct Evidence {
+    pub dispute: Pubkey,
+    pub index: u32,
+    pub submitter: Pubkey,
+    pub content_hash: [u8; 32],
+    pub uri: String, // (max ~128 characters)
+    pub submitted_at: i64,
+}
+
+impl Evidence {
+    // Size: dispute (32) + index (4) + submitter (32) + content_hash (32) + uri (4+128) + submitted_at (8) = 240.
+    pub const LEN: usize = 240;
 }
 
 #[event]
 pub struct DisputeRaised {
     pub produce: Pubkey,
+    pub dispute: Pubkey,
+    pub index: u32,
     pub raiser: Pubkey,
+    pub reason: DisputeReason,
+    pub bond_amount: u64,
     pub timestamp: i64,
 }
 
-/// Raises a dispute for a produce batch.
-pub fn raise_dispute(ctx: Context<RaiseDispute>, description: String) -> Result<()> {
-    let dispute = &mut ctx.accounts.dispute;
-    dispute.produce = ctx.accounts.produce.key();
-    dispute.raiser = ctx.accounts.raiser.key();
+#[event]
+pub struct DisputeAnswered {
+    pub dispute: Pubkey,
+    pub respondent: Pubkey,
+    pub response_bond: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub produce: Pubkey,
+    pub resolution: bool,
+    pub farmer_amount: u64,
+    pub transporter_amount: u64,
+    pub buyer_refund: u64,
+    pub bond_recipient: Pubkey,
+    pub appeal_bond_recipient: Pubkey,
+    pub response_bond_recipient: Pubkey,
+    pub arbitration_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeRuled {
+    pub dispute: Pubkey,
+    pub resolution: bool,
+    pub appeal_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeTimedOut {
+    pub dispute: Pubkey,
+    pub flagged_arbitrators: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeAppealed {
+    pub dispute: Pubkey,
+    pub appellant: Pubkey,
+    pub appeal_bond: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PanelAssigned {
+    pub dispute: Pubkey,
+    pub panel: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PanelRulingSubmitted {
+    pub dispute: Pubkey,
+    pub arbitrator: Pubkey,
+    pub resolution: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EvidenceSubmitted {
+    pub dispute: Pubkey,
+    pub index: u32,
+    pub submitter: Pubkey,
+    pub content_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Creates the dispute configuration. Governance admin only.
+pub fn initialize_dispute_config(
+    ctx: Context<InitializeDisputeConfig>,
+    params: DisputeParams,
+) -> Result<()> {
+    params.validate()?;
+    let dispute_config = &mut ctx.accounts.dispute_config;
+    dispute_config.params = params;
+    dispute_config.bump = ctx.bumps.dispute_config;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeDisputeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + DisputeConfig::LEN,
+        seeds = [b"dispute_config"],
+        bump
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Updates the dispute configuration. Governance admin only.
+pub fn update_dispute_config(ctx: Context<UpdateDisputeConfig>, params: DisputeParams) -> Result<()> {
+    params.validate()?;
+    ctx.accounts.dispute_config.params = params;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateDisputeConfig<'info> {
+    #[account(mut, seeds = [b"dispute_config"], bump = dispute_config.bump)]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    pub admin: Signer<'info>,
+}
+
+/// Raises a dispute for a produce batch. Only parties to the shipment may
+/// raise one, and they must lock the configured dispute bond in the vault.
+/// There must be escrow left to rule on: a settled batch cannot be disputed.
+pub fn raise_dispute(
+    ctx: Context<RaiseDispute>,
+    reason: DisputeReason,
+    description: String,
+) -> Result<()> {
+    let bond_amount = open_dispute(
+        &mut ctx.accounts.dispute,
+        &mut ctx.accounts.produce,
+        &ctx.accounts.dispute_config.params,
+        &mut ctx.accounts.raiser_reputation,
+        ctx.accounts.raiser.key(),
+        reason,
+        description,
+    )?;
+    if bond_amount > 0 {
+        // The raiser covers any transfer fee so the escrow holds the full bond.
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.raiser_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.escrow.to_account_info(),
+            authority: ctx.accounts.raiser.to_account_info(),
+        };
+        token_interface::transfer_checked(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+            gross_up(&ctx.accounts.mint, bond_amount)?,
+            ctx.accounts.mint.decimals,
+        )?;
+        collect_fee(
+            &ctx.accounts.token_program,
+            &ctx.accounts.raiser_token_account,
+            &ctx.accounts.raiser,
+            &ctx.accounts.treasury_vault,
+            &ctx.accounts.mint,
+            ctx.accounts.produce.key(),
+            protocol_fee(bond_amount, ctx.accounts.treasury.bond_fee_bps),
+        )?;
+    }
+    Ok(())
+}
+
+/// Native SOL counterpart of `raise_dispute`: the bond is locked in the
+/// batch's SOL escrow.
+pub fn raise_dispute_sol(
+    ctx: Context<RaiseDisputeSol>,
+    reason: DisputeReason,
+    description: String,
+) -> Result<()> {
+    let bond_amount = open_dispute(
+        &mut ctx.accounts.dispute,
+        &mut ctx.accounts.produce,
+        &ctx.accounts.dispute_config.params,
+        &mut ctx.accounts.raiser_reputation,
+        ctx.accounts.raiser.key(),
+        reason,
+        description,
+    )?;
+    let accounts = &ctx.accounts;
+    lock_sol_bond(
+        &accounts.system_program,
+        &accounts.raiser,
+        &accounts.sol_escrow,
+        &accounts.treasury,
+        accounts.produce.key(),
+        bond_amount,
+    )
+}
+
+/// Records a new dispute on the batch and returns the bond the raiser owes.
+fn open_dispute(
+    dispute: &mut Account<Dispute>,
+    produce: &mut Account<Produce>,
+    params: &DisputeParams,
+    raiser_reputation: &mut Reputation,
+    raiser: Pubkey,
+    reason: DisputeReason,
+    description: String,
+) -> Result<u64> {
+    require!(description.len() <= 128, ErrorCode::DescriptionTooLong);
+    require!(produce.is_party(&raiser), ErrorCode::Unauthorized);
+    require!(
+        produce.milestones_released & Milestone::Quality.mask() == 0 && produce.buyer != Pubkey::default(),
+        ErrorCode::InvalidStatus
+    );
+
+    let bond_amount = params.bond_amount;
+    dispute.produce = produce.key();
+    dispute.index = produce.dispute_count;
+    dispute.raiser = raiser;
     dispute.description = description;
     dispute.created_at = Clock::get()?.unix_timestamp;
     dispute.resolved = false;
     dispute.resolution = false;
-    
+    dispute.reason = reason;
+    dispute.bond_amount = bond_amount;
+    dispute.evidence_count = 0;
+    dispute.evidence_deadline = dispute
+        .created_at
+        .checked_add(params.evidence_period)
+        .ok_or(ErrorCode::Overflow)?;
+    dispute.escrow_share = produce.hold_for_dispute();
+    dispute.panel_required = params.panel_size > 1 && dispute.escrow_share >= params.panel_threshold;
+    dispute.panel = Vec::new();
+    dispute.rulings = Vec::new();
+    dispute.dissenters = Vec::new();
+    dispute.ruled_at = 0;
+    dispute.appeal_deadline = 0;
+    dispute.settled = false;
+    dispute.appeal_round = 0;
+    dispute.appellant = Pubkey::default();
+    dispute.appeal_bond = 0;
+    dispute.appealed_resolution = false;
+    dispute.prior_arbitrators = Vec::new();
+    dispute.arbitration_fee = params.arbitration_fee;
+    dispute.ruling_due_at = dispute
+        .evidence_deadline
+        .checked_add(params.max_ruling_time)
+        .ok_or(ErrorCode::Overflow)?;
+    dispute.timed_out = false;
+    dispute.fee_collected = 0;
+    dispute.fee_claimants = Vec::new();
+    dispute.response_bond = 0;
+
     // Mark the produce as having a dispute.
-    let produce = &mut ctx.accounts.produce;
     produce.dispute_raised = true;
+    produce.dispute_count = produce.dispute_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    produce.open_disputes = produce.open_disputes.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    raiser_reputation.record_dispute_raised(dispute.created_at);
 
     emit!(DisputeRaised {
         produce: produce.key(),
-        raiser: ctx.accounts.raiser.key(),
+        dispute: dispute.key(),
+        index: dispute.index,
+        raiser,
+        reason,
+        bond_amount,
         timestamp: dispute.created_at,
     });
-    Ok(())
+    Ok(bond_amount)
+}
+
+/// Locks a bond in a native SOL batch's escrow and charges the bond fee.
+fn lock_sol_bond<'info>(
+    system_program: &Program<'info, System>,
+    payer: &Signer<'info>,
+    sol_escrow: &Account<'info, SolEscrow>,
+    treasury: &Account<'info, Treasury>,
+    produce: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let cpi_accounts = system_program::Transfer {
+        from: payer.to_account_info(),
+        to: sol_escrow.to_account_info(),
+    };
+    system_program::transfer(CpiContext::new(system_program.to_account_info(), cpi_accounts), amount)?;
+    collect_sol_fee(
+        system_program,
+        payer,
+        treasury,
+        produce,
+        protocol_fee(amount, treasury.bond_fee_bps),
+    )
 }
 
 #[derive(Accounts)]
@@ -56,15 +627,287 @@ pub struct RaiseDispute<'info> {
         init,
         payer = raiser,
         space = 8 + Dispute::LEN,
-        seeds = [b"dispute", produce.key().as_ref()],
+        seeds = [b"dispute", produce.key().as_ref(), &produce.dispute_count.to_le_bytes()],
         bump
     )]
     pub dispute: Account<'info, Dispute>,
+    #[account(seeds = [b"dispute_config"], bump = dispute_config.bump)]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    #[account(mut, seeds = [b"reputation", raiser.key.as_ref()], bump = raiser_reputation.bump)]
+    pub raiser_reputation: Account<'info, Reputation>,
+    #[account(mut)]
+    pub raiser_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"escrow", produce.key().as_ref(), produce.settlement_mint.as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = produce.settlement_mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut, seeds = [b"treasury_vault", produce.settlement_mint.as_ref()], bump)]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RaiseDisputeSol<'info> {
+    #[account(mut)]
+    pub produce: Account<'info, Produce>,
+    #[account(mut)]
+    pub raiser: Signer<'info>,
+    #[account(
+        init,
+        payer = raiser,
+        space = 8 + Dispute::LEN,
+        seeds = [b"dispute", produce.key().as_ref(), &produce.dispute_count.to_le_bytes()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    #[account(seeds = [b"dispute_config"], bump = dispute_config.bump)]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    #[account(mut, seeds = [b"reputation", raiser.key.as_ref()], bump = raiser_reputation.bump)]
+    pub raiser_reputation: Account<'info, Reputation>,
+    #[account(mut, seeds = [b"sol_escrow", produce.key().as_ref()], bump = sol_escrow.bump)]
+    pub sol_escrow: Account<'info, SolEscrow>,
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Answers a dispute: the respondent locks a bond matching the raiser's
+/// before the evidence deadline, so that whichever side loses has a bond to
+/// pay the arbitration fee from.
+pub fn answer_dispute(ctx: Context<AnswerDispute>) -> Result<()> {
+    let response_bond = record_answer(
+        &mut ctx.accounts.dispute,
+        &ctx.accounts.produce,
+        ctx.accounts.respondent.key(),
+    )?;
+    // The respondent covers any transfer fee so the escrow holds the full bond.
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.respondent_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.escrow.to_account_info(),
+        authority: ctx.accounts.respondent.to_account_info(),
+    };
+    token_interface::transfer_checked(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+        gross_up(&ctx.accounts.mint, response_bond)?,
+        ctx.accounts.mint.decimals,
+    )?;
+    collect_fee(
+        &ctx.accounts.token_program,
+        &ctx.accounts.respondent_token_account,
+        &ctx.accounts.respondent,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.mint,
+        ctx.accounts.produce.key(),
+        protocol_fee(response_bond, ctx.accounts.treasury.bond_fee_bps),
+    )
+}
+
+/// Native SOL counterpart of `answer_dispute`.
+pub fn answer_dispute_sol(ctx: Context<AnswerDisputeSol>) -> Result<()> {
+    let response_bond = record_answer(
+        &mut ctx.accounts.dispute,
+        &ctx.accounts.produce,
+        ctx.accounts.respondent.key(),
+    )?;
+    let accounts = &ctx.accounts;
+    lock_sol_bond(
+        &accounts.system_program,
+        &accounts.respondent,
+        &accounts.sol_escrow,
+        &accounts.treasury,
+        accounts.produce.key(),
+        response_bond,
+    )
+}
+
+/// Records the respondent's answer and returns the bond they owe.
+fn record_answer(dispute: &mut Account<Dispute>, produce: &Produce, respondent: Pubkey) -> Result<u64> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(!dispute.resolved, ErrorCode::AlreadyResolved);
+    require!(now < dispute.evidence_deadline, ErrorCode::EvidenceClosed);
+    require!(respondent == dispute.respondent(produce), ErrorCode::Unauthorized);
+    require!(dispute.response_bond == 0 && dispute.bond_amount > 0, ErrorCode::InvalidStatus);
+    dispute.response_bond = dispute.bond_amount;
+
+    emit!(DisputeAnswered {
+        dispute: dispute.key(),
+        respondent,
+        response_bond: dispute.response_bond,
+        timestamp: now,
+    });
+    Ok(dispute.response_bond)
+}
+
+#[derive(Accounts)]
+pub struct AnswerDispute<'info> {
+    #[account(mut, has_one = produce)]
+    pub dispute: Account<'info, Dispute>,
+    pub produce: Account<'info, Produce>,
+    #[account(mut)]
+    pub respondent: Signer<'info>,
+    #[account(mut)]
+    pub respondent_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"escrow", produce.key().as_ref(), produce.settlement_mint.as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = produce.settlement_mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut, seeds = [b"treasury_vault", produce.settlement_mint.as_ref()], bump)]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct AnswerDisputeSol<'info> {
+    #[account(mut, has_one = produce)]
+    pub dispute: Account<'info, Dispute>,
+    pub produce: Account<'info, Produce>,
+    #[account(mut)]
+    pub respondent: Signer<'info>,
+    #[account(mut, seeds = [b"sol_escrow", produce.key().as_ref()], bump = sol_escrow.bump)]
+    pub sol_escrow: Account<'info, SolEscrow>,
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Attaches a piece of evidence to an open dispute. Parties to the shipment
+/// may submit evidence until the evidence deadline.
+pub fn submit_evidence(
+    ctx: Context<SubmitEvidence>,
+    content_hash: [u8; 32],
+    uri: String,
+) -> Result<()> {
+    require!(uri.len() <= 128, ErrorCode::UriTooLong);
+    let submitter = ctx.accounts.submitter.key();
+    let dispute = &mut ctx.accounts.dispute;
+    require!(!dispute.resolved, ErrorCode::AlreadyResolved);
+    require!(
+        ctx.accounts.produce.is_party(&submitter) || dispute.raiser == submitter,
+        ErrorCode::Unauthorized
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < dispute.evidence_deadline, ErrorCode::EvidenceClosed);
+
+    let evidence = &mut ctx.accounts.evidence;
+    evidence.dispute = dispute.key();
+    evidence.index = dispute.evidence_count;
+    evidence.submitter = submitter;
+    evidence.content_hash = content_hash;
+    evidence.uri = uri;
+    evidence.submitted_at = now;
+    dispute.evidence_count = dispute.evidence_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+
+    emit!(EvidenceSubmitted {
+        dispute: dispute.key(),
+        index: evidence.index,
+        submitter,
+        content_hash,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitEvidence<'info> {
+    #[account(mut, has_one = produce)]
+    pub dispute: Account<'info, Dispute>,
+    pub produce: Account<'info, Produce>,
+    #[account(
+        init,
+        payer = submitter,
+        space = 8 + Evidence::LEN,
+        seeds = [b"evidence", dispute.key().as_ref(), &dispute.evidence_count.to_le_bytes()],
+        bump
+    )]
+    pub evidence: Account<'info, Evidence>,
+    #[account(mut)]
+    pub submitter: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// Resolves a dispute. The arbitrator (with proper role) decides the outcome.
-pub fn resolve_dispute(ctx: Context<ResolveDispute>, resolution: bool) -> Result<()> {
+/// Draws a dispute's arbitrators from the registry, seeded from the latest
+/// slot hash: a panel for large-value or appealed disputes, otherwise one.
+pub fn assign_panel(ctx: Context<AssignPanel>) -> Result<()> {
+    let produce = &ctx.accounts.produce;
+    let dispute = &mut ctx.accounts.dispute;
+    require!(!dispute.resolved, ErrorCode::AlreadyResolved);
+    require!(dispute.panel.is_empty(), ErrorCode::PanelAlreadyAssigned);
+    let panel_size = if dispute.panel_required {
+        ctx.accounts.dispute_config.params.panel_size as usize
+    } else {
+        1
+    };
+
+    let mut candidates: Vec<Pubkey> = ctx
+        .accounts
+        .registry
+        .arbitrators
+        .iter()
+        .filter(|entry| entry.status == ArbitratorStatus::Active)
+        .map(|entry| entry.arbitrator)
+        .filter(|arbitrator| !produce.is_party(arbitrator) && *arbitrator != dispute.raiser)
+        .filter(|arbitrator| !dispute.prior_arbitrators.contains(arbitrator))
+        .collect();
+    require!(candidates.len() >= panel_size, ErrorCode::NotEnoughArbitrators);
+
+    // SlotHashes data: u64 entry count, then (slot u64, hash [u8; 32]) entries, newest first.
+    let slot_hashes = ctx.accounts.slot_hashes.try_borrow_data()?;
+    require!(slot_hashes.len() >= 48, ErrorCode::InvalidConfig);
+    let mut seed = hashv(&[&slot_hashes[16..48], dispute.key().as_ref()]).to_bytes();
+    // Partial Fisher-Yates shuffle over the candidates.
+    for i in 0..panel_size {
+        let mut random = [0u8; 8];
+        random.copy_from_slice(&seed[..8]);
+        let j = i + (u64::from_le_bytes(random) % (candidates.len() - i) as u64) as usize;
+        candidates.swap(i, j);
+        seed = hashv(&[&seed]).to_bytes();
+    }
+    candidates.truncate(panel_size);
+    dispute.panel = candidates;
+
+    emit!(PanelAssigned {
+        dispute: dispute.key(),
+        panel: dispute.panel.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AssignPanel<'info> {
+    #[account(mut, has_one = produce)]
+    pub dispute: Account<'info, Dispute>,
+    pub produce: Account<'info, Produce>,
+    #[account(seeds = [b"dispute_config"], bump = dispute_config.bump)]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    #[account(seeds = [b"arbitrator_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ArbitratorRegistry>,
+    /// CHECK: Address is constrained to the SlotHashes sysvar; only its raw bytes are read.
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+/// Records an assigned arbitrator's ruling once the evidence period has
+/// closed; panel disputes are decided by majority.
+pub fn resolve_dispute(
+    ctx: Context<ResolveDispute>,
+    resolution: bool,
+    split: SettlementSplit,
+) -> Result<()> {
     // Ensure the caller is an Arbitrator.
     let arbitrator_account = &ctx.accounts.arbitrator_account;
     if let crate::participant::ParticipantRole::Arbitrator = arbitrator_account.role {
@@ -72,31 +915,89 @@ pub fn resolve_dispute(ctx: Context<ResolveDispute>, resolution: bool) -> Result
     } else {
         return Err(ErrorCode::Unauthorized.into());
     }
+    split.validate()?;
     
+    let arbitrator = ctx.accounts.arbitrator.key();
+    let now = Clock::get()?.unix_timestamp;
     let dispute = &mut ctx.accounts.dispute;
     require!(!dispute.resolved, ErrorCode::AlreadyResolved);
+    require!(now >= dispute.evidence_deadline, ErrorCode::EvidencePeriodOpen);
+    require!(
+        !ctx.accounts.produce.is_party(&arbitrator) && dispute.raiser != arbitrator,
+        ErrorCode::ConflictOfInterest
+    );
+
+    require!(!dispute.panel.is_empty(), ErrorCode::PanelNotAssigned);
+    require!(dispute.panel.contains(&arbitrator), ErrorCode::Unauthorized);
+    let entry = ctx.accounts.registry.require_active(&arbitrator)?;
+    entry.case_count = entry.case_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+
+    let ruling = PanelRuling {
+        arbitrator,
+        resolution,
+        split,
+    };
+    let (resolution, split) = if dispute.panel_required {
+        require!(
+            !dispute.rulings.iter().any(|ruling| ruling.arbitrator == arbitrator),
+            ErrorCode::AlreadyVoted
+        );
+        dispute.rulings.push(ruling);
+        emit!(PanelRulingSubmitted {
+            dispute: dispute.key(),
+            arbitrator,
+            resolution,
+            timestamp: now,
+        });
+        match dispute.majority_ruling() {
+            Some(outcome) => outcome,
+            None => return Ok(()), // waiting for more panel members
+        }
+    } else {
+        dispute.rulings.push(ruling);
+        (resolution, split)
+    };
+
+    // Reputation: count who ended up in the majority and who dissented.
+    let registry = &mut ctx.accounts.registry;
+    for ruling in dispute.rulings.iter() {
+        if let Some(entry) = registry.find_mut(&ruling.arbitrator) {
+            if ruling.resolution == resolution {
+                entry.majority_count = entry.majority_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+            } else {
+                entry.dissent_count = entry.dissent_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+            }
+        }
+    }
+
     dispute.resolved = true;
     dispute.resolution = resolution;
-    
-    // Update produce status based on the resolution.
-    let produce = &mut ctx.accounts.produce;
-    produce.dispute_raised = false;
-    if resolution {
-        // Resolved in favor of original terms.
-        produce.status = ProduceStatus::Delivered;
+    dispute.split = split;
+    dispute.ruled_at = now;
+    // Once the appeal budget is spent the ruling is final straight away.
+    dispute.appeal_deadline = if dispute.appeal_round < MAX_APPEALS {
+        now.checked_add(ctx.accounts.dispute_config.params.appeal_window)
+            .ok_or(ErrorCode::Overflow)?
     } else {
-        // Dispute stands; mark as disputed.
-        produce.status = ProduceStatus::Disputed;
-    }
+        now
+    };
+
+    emit!(DisputeRuled {
+        dispute: dispute.key(),
+        resolution,
+        appeal_deadline: dispute.appeal_deadline,
+        timestamp: now,
+    });
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct ResolveDispute<'info> {
-    #[account(mut)]
+    #[account(mut, has_one = produce)]
     pub dispute: Account<'info, Dispute>,
-    #[account(mut)]
     pub produce: Account<'info, Produce>,
+    #[account(seeds = [b"dispute_config"], bump = dispute_config.bump)]
+    pub dispute_config: Account<'info, DisputeConfig>,
     #[account(
         mut,
         seeds = [b"participant", arbitrator.key.as_ref()],
@@ -104,5 +1005,632 @@ pub struct ResolveDispute<'info> {
         constraint = arbitrator_account.owner == arbitrator.key()
     )]
     pub arbitrator_account: Account<'info, crate::participant::Participant>,
+    #[account(mut, seeds = [b"arbitrator_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ArbitratorRegistry>,
     pub arbitrator: Signer<'info>,
 }
+
+/// Appeals a ruling. Only the losing party may appeal, within the appeal
+/// window, by locking the (larger) appeal bond. The dispute then goes to a
+/// fresh panel that excludes every arbitrator who ruled on it before.
+pub fn appeal_dispute(ctx: Context<AppealDispute>) -> Result<()> {
+    let appeal_bond = begin_appeal(
+        &mut ctx.accounts.dispute,
+        &ctx.accounts.produce,
+        &ctx.accounts.dispute_config.params,
+        ctx.accounts.appellant.key(),
+    )?;
+    if appeal_bond > 0 {
+        // The appellant covers any transfer fee so the escrow holds the full bond.
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.appellant_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.escrow.to_account_info(),
+            authority: ctx.accounts.appellant.to_account_info(),
+        };
+        token_interface::transfer_checked(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+            gross_up(&ctx.accounts.mint, appeal_bond)?,
+            ctx.accounts.mint.decimals,
+        )?;
+        collect_fee(
+            &ctx.accounts.token_program,
+            &ctx.accounts.appellant_token_account,
+            &ctx.accounts.appellant,
+            &ctx.accounts.treasury_vault,
+            &ctx.accounts.mint,
+            ctx.accounts.produce.key(),
+            protocol_fee(appeal_bond, ctx.accounts.treasury.bond_fee_bps),
+        )?;
+    }
+    Ok(())
+}
+
+/// Native SOL counterpart of `appeal_dispute`.
+pub fn appeal_dispute_sol(ctx: Context<AppealDisputeSol>) -> Result<()> {
+    let appeal_bond = begin_appeal(
+        &mut ctx.accounts.dispute,
+        &ctx.accounts.produce,
+        &ctx.accounts.dispute_config.params,
+        ctx.accounts.appellant.key(),
+    )?;
+    let accounts = &ctx.accounts;
+    lock_sol_bond(
+        &accounts.system_program,
+        &accounts.appellant,
+        &accounts.sol_escrow,
+        &accounts.treasury,
+        accounts.produce.key(),
+        appeal_bond,
+    )
+}
+
+/// Reopens a ruled dispute for appeal and returns the bond the appellant owes.
+fn begin_appeal(
+    dispute: &mut Account<Dispute>,
+    produce: &Produce,
+    params: &DisputeParams,
+    appellant: Pubkey,
+) -> Result<u64> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(dispute.resolved && !dispute.settled, ErrorCode::InvalidStatus);
+    require!(dispute.appeal_round < MAX_APPEALS, ErrorCode::AppealNotAllowed);
+    require!(now < dispute.appeal_deadline, ErrorCode::AppealWindowClosed);
+    require!(dispute.is_losing_party(produce, &appellant), ErrorCode::Unauthorized);
+
+    let appeal_bond = params.appeal_bond;
+    let mut previous: Vec<Pubkey> = dispute.rulings.iter().map(|ruling| ruling.arbitrator).collect();
+    previous.extend(dispute.panel.iter().copied());
+    dispute.prior_arbitrators.extend(previous);
+    dispute.prior_arbitrators.sort();
+    dispute.prior_arbitrators.dedup();
+    dispute.appeal_round += 1;
+    dispute.appellant = appellant;
+    dispute.appeal_bond = appeal_bond;
+    dispute.appealed_resolution = dispute.resolution;
+    dispute.resolved = false;
+    dispute.panel_required = true;
+    dispute.panel = Vec::new();
+    dispute.rulings = Vec::new();
+    dispute.dissenters = Vec::new();
+    dispute.ruling_due_at = now
+        .checked_add(params.max_ruling_time)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit!(DisputeAppealed {
+        dispute: dispute.key(),
+        appellant,
+        appeal_bond,
+        timestamp: now,
+    });
+    Ok(appeal_bond)
+}
+
+#[derive(Accounts)]
+pub struct AppealDispute<'info> {
+    #[account(mut, has_one = produce)]
+    pub dispute: Account<'info, Dispute>,
+    pub produce: Account<'info, Produce>,
+    #[account(seeds = [b"dispute_config"], bump = dispute_config.bump)]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    #[account(mut)]
+    pub appellant: Signer<'info>,
+    #[account(mut)]
+    pub appellant_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"escrow", produce.key().as_ref(), produce.settlement_mint.as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = produce.settlement_mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut, seeds = [b"treasury_vault", produce.settlement_mint.as_ref()], bump)]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct AppealDisputeSol<'info> {
+    #[account(mut, has_one = produce)]
+    pub dispute: Account<'info, Dispute>,
+    pub produce: Account<'info, Produce>,
+    #[account(seeds = [b"dispute_config"], bump = dispute_config.bump)]
+    pub dispute_config: Account<'info, DisputeConfig>,
+    #[account(mut)]
+    pub appellant: Signer<'info>,
+    #[account(mut, seeds = [b"sol_escrow", produce.key().as_ref()], bump = sol_escrow.bump)]
+    pub sol_escrow: Account<'info, SolEscrow>,
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Resolves a dispute nobody ruled on in time and flags the arbitrators who
+/// missed it. Anyone may call this.
+pub fn timeout_dispute(ctx: Context<TimeoutDispute>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let dispute = &mut ctx.accounts.dispute;
+    require!(!dispute.resolved, ErrorCode::AlreadyResolved);
+    require!(now >= dispute.ruling_due_at, ErrorCode::RulingPeriodOpen);
+
+    let flagged: Vec<Pubkey> = dispute
+        .panel
+        .iter()
+        .filter(|member| !dispute.rulings.iter().any(|ruling| ruling.arbitrator == **member))
+        .copied()
+        .collect();
+    let registry = &mut ctx.accounts.registry;
+    for arbitrator in flagged.iter() {
+        if let Some(entry) = registry.find_mut(arbitrator) {
+            entry.timeout_count = entry.timeout_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+        }
+    }
+
+    dispute.resolved = true;
+    if dispute.appeal_round > 0 {
+        dispute.resolution = dispute.appealed_resolution;
+    } else {
+        dispute.resolution = false;
+        dispute.split = SettlementSplit {
+            farmer_bps: 0,
+            transporter_bps: 0,
+            buyer_bps: BPS_DENOMINATOR as u16,
+        };
+    }
+    dispute.ruled_at = now;
+    dispute.appeal_deadline = now;
+    dispute.timed_out = true;
+
+    emit!(DisputeTimedOut {
+        dispute: dispute.key(),
+        flagged_arbitrators: flagged,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TimeoutDispute<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(mut, seeds = [b"arbitrator_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ArbitratorRegistry>,
+}
+
+/// Pays out a ruling's bonds, fee and escrow share once its appeal window
+/// has passed. Anyone may call this.
+pub fn finalize_dispute(ctx: Context<FinalizeDispute>) -> Result<()> {
+    let payouts = settle_dispute(
+        &mut ctx.accounts.dispute,
+        &mut ctx.accounts.produce,
+        &mut ctx.accounts.registry,
+        &mut ctx.accounts.loser_reputation,
+    )?;
+    let accounts = &ctx.accounts;
+    for (to, (party, amount)) in [
+        &accounts.farmer_payment_account,
+        &accounts.transporter_payment_account,
+        &accounts.buyer_payment_account,
+        &accounts.bond_recipient_account,
+        &accounts.appeal_bond_recipient_account,
+        &accounts.response_bond_recipient_account,
+    ]
+    .into_iter()
+    .zip(payouts.transfers())
+    {
+        require_payee(to, party, amount)?;
+        transfer_from_vault(&accounts.token_program, &accounts.escrow, to, &accounts.mint, &accounts.vault, amount)?;
+    }
+
+    if payouts.settles_batch {
+        let accounts = ctx.accounts;
+        let settlement = settlement_breakdown(accounts.produce.key(), &accounts.produce, payouts.buyer_refund)?;
+        let treasury = accounts.treasury_vault.key();
+        accounts
+            .invoicing
+            .issue(&ctx.bumps.invoicing, &accounts.produce, &settlement, treasury)?;
+        emit!(settlement);
+    }
+    Ok(())
+}
+
+/// Native SOL counterpart of `finalize_dispute`, paying out of the batch's
+/// SOL escrow.
+pub fn finalize_dispute_sol(ctx: Context<FinalizeDisputeSol>) -> Result<()> {
+    let payouts = settle_dispute(
+        &mut ctx.accounts.dispute,
+        &mut ctx.accounts.produce,
+        &mut ctx.accounts.registry,
+        &mut ctx.accounts.loser_reputation,
+    )?;
+    let accounts = &ctx.accounts;
+    let sol_escrow = accounts.sol_escrow.to_account_info();
+    for (to, (party, amount)) in [
+        &accounts.farmer,
+        &accounts.transporter,
+        &accounts.buyer,
+        &accounts.bond_recipient,
+        &accounts.appeal_bond_recipient,
+        &accounts.response_bond_recipient,
+    ]
+    .into_iter()
+    .zip(payouts.transfers())
+    {
+        if amount > 0 {
+            require!(
+                party != Pubkey::default() && to.key() == party,
+                ErrorCode::InvalidPayoutAccount
+            );
+        }
+        transfer_lamports(&sol_escrow, &to.to_account_info(), amount)?;
+    }
+
+    if payouts.settles_batch {
+        let accounts = ctx.accounts;
+        let settlement = settlement_breakdown(accounts.produce.key(), &accounts.produce, payouts.buyer_refund)?;
+        let treasury = accounts.treasury.key();
+        accounts
+            .invoicing
+            .issue(&ctx.bumps.invoicing, &accounts.produce, &settlement, treasury)?;
+        emit!(settlement);
+    }
+    Ok(())
+}
+
+/// What a settled dispute pays out of the batch escrow, and to whom.
+struct DisputePayouts {
+    farmer_payee: Pubkey,
+    farmer_amount: u64,
+    transporter: Pubkey,
+    transporter_amount: u64,
+    buyer: Pubkey,
+    buyer_refund: u64,
+    bond_recipient: Pubkey,
+    bond_amount: u64,
+    appeal_bond_recipient: Pubkey,
+    appeal_bond: u64,
+    response_bond_recipient: Pubkey,
+    response_bond: u64,
+    settles_batch: bool, // first payout of the batch escrow
+}
+
+impl DisputePayouts {
+    /// Recipients and amounts in the order of the finalize accounts.
+    fn transfers(&self) -> [(Pubkey, u64); 6] {
+        [
+            (self.farmer_payee, self.farmer_amount),
+            (self.transporter, self.transporter_amount),
+            (self.buyer, self.buyer_refund),
+            (self.bond_recipient, self.bond_amount),
+            (self.appeal_bond_recipient, self.appeal_bond),
+            (self.response_bond_recipient, self.response_bond),
+        ]
+    }
+}
+
+/// Marks a ruled dispute settled, charges the arbitration fee and works out
+/// the payouts; shared by both finalize instructions.
+fn settle_dispute(
+    dispute: &mut Dispute,
+    produce: &mut Account<Produce>,
+    registry: &mut ArbitratorRegistry,
+    loser_reputation: &mut Reputation,
+) -> Result<DisputePayouts> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(dispute.resolved, ErrorCode::InvalidStatus);
+    require!(!dispute.settled, ErrorCode::AlreadyResolved);
+    require!(now >= dispute.appeal_deadline, ErrorCode::AppealWindowOpen);
+    dispute.settled = true;
+
+    // Each dispute pays out only the share of the escrow it held. The batch
+    // keeps its status.
+    produce.open_disputes = produce.open_disputes.saturating_sub(1);
+    produce.dispute_raised = produce.open_disputes > 0;
+    let already_settled = produce.settled;
+    let releasable = produce.release_dispute_share(dispute.escrow_share);
+    // A dispute settled by the default rule was never adjudicated.
+    if dispute.adjudicated() {
+        loser_reputation.record_dispute_lost(now);
+    }
+
+    // Collect the arbitration fee from the losing side's bonds, then from
+    // its share of the split.
+    let winners = dispute.winners();
+    let mut fee_due = if dispute.timed_out || winners.is_empty() {
+        0
+    } else {
+        dispute.arbitration_fee
+    };
+    let mut bond_amount = dispute.bond_amount;
+    let mut response_bond = dispute.response_bond;
+    let loser_bond = if dispute.resolution { &mut bond_amount } else { &mut response_bond };
+    let taken = fee_due.min(*loser_bond);
+    *loser_bond -= taken;
+    fee_due -= taken;
+    let mut appeal_bond = dispute.appeal_bond;
+    if dispute.appeal_bond_recipient(produce) != dispute.appellant {
+        let taken = fee_due.min(appeal_bond);
+        appeal_bond -= taken;
+        fee_due -= taken;
+    }
+    let (mut farmer_amount, mut transporter_amount, mut buyer_refund) =
+        dispute.split.apply(releasable)?;
+    let loser = dispute.loser(produce);
+    let loser_share = if loser == produce.farmer {
+        &mut farmer_amount
+    } else if loser == produce.transporter {
+        &mut transporter_amount
+    } else {
+        &mut buyer_refund
+    };
+    let taken = fee_due.min(*loser_share);
+    *loser_share -= taken;
+    fee_due -= taken;
+    let arbitration_fee = if dispute.timed_out || winners.is_empty() {
+        0
+    } else {
+        dispute.arbitration_fee - fee_due
+    };
+
+    // Fees stay in the batch escrow until each arbitrator claims their share.
+    dispute.fee_collected = arbitration_fee;
+    for arbitrator in winners.iter() {
+        let share = dispute.fee_share(arbitrator).unwrap_or(0);
+        if let Some(entry) = registry.find_mut(arbitrator) {
+            entry.fees_earned = entry.fees_earned.checked_add(share).ok_or(ErrorCode::Overflow)?;
+            entry.fees_unclaimed = entry.fees_unclaimed.checked_add(share).ok_or(ErrorCode::Overflow)?;
+        }
+    }
+
+    produce.farmer_released = produce.farmer_released.saturating_add(farmer_amount);
+    produce.transporter_released = produce.transporter_released.saturating_add(transporter_amount);
+    let payouts = DisputePayouts {
+        farmer_payee: produce.farmer_payee(),
+        farmer_amount,
+        transporter: produce.transporter,
+        transporter_amount,
+        buyer: produce.buyer,
+        buyer_refund,
+        bond_recipient: dispute.bond_recipient(produce),
+        bond_amount,
+        appeal_bond_recipient: dispute.appeal_bond_recipient(produce),
+        appeal_bond,
+        response_bond_recipient: dispute.response_bond_recipient(produce),
+        response_bond,
+        settles_batch: produce.settled && !already_settled,
+    };
+
+    emit!(DisputeResolved {
+        produce: produce.key(),
+        resolution: dispute.resolution,
+        farmer_amount,
+        transporter_amount,
+        buyer_refund,
+        bond_recipient: payouts.bond_recipient,
+        appeal_bond_recipient: payouts.appeal_bond_recipient,
+        response_bond_recipient: payouts.response_bond_recipient,
+        arbitration_fee,
+        timestamp: now,
+    });
+    Ok(payouts)
+}
+
+/// Checks that a non-zero payout goes to a token account owned by the party.
+fn require_payee(account: &TokenAccount, party: Pubkey, amount: u64) -> Result<()> {
+    if amount > 0 {
+        require!(
+            party != Pubkey::default() && account.owner == party,
+            ErrorCode::InvalidPayoutAccount
+        );
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeDispute<'info> {
+    #[account(mut, has_one = produce)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(mut)]
+    pub produce: Account<'info, Produce>,
+    #[account(mut, seeds = [b"arbitrator_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ArbitratorRegistry>,
+    #[account(
+        mut,
+        seeds = [b"reputation", loser_reputation.owner.as_ref()],
+        bump = loser_reputation.bump,
+        constraint = loser_reputation.owner == dispute.loser(&produce) @ ErrorCode::InvalidReputationAccount
+    )]
+    pub loser_reputation: Account<'info, Reputation>,
+    #[account(seeds = [b"vault"], bump = vault.bump)]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"escrow", produce.key().as_ref(), produce.settlement_mint.as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = produce.settlement_mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub farmer_payment_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub transporter_payment_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub buyer_payment_account: InterfaceAccount<'info, TokenAccount>,
+    /// Receives the dispute bond: the raiser if upheld, otherwise the counterparty.
+    #[account(mut)]
+    pub bond_recipient_account: InterfaceAccount<'info, TokenAccount>,
+    /// Receives the appeal bond, if any: the appellant if the appeal succeeded.
+    #[account(mut)]
+    pub appeal_bond_recipient_account: InterfaceAccount<'info, TokenAccount>,
+    /// Receives the response bond, if any: the raiser if upheld, otherwise the respondent.
+    #[account(mut)]
+    pub response_bond_recipient_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(seeds = [b"treasury_vault", produce.settlement_mint.as_ref()], bump)]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+    pub invoicing: IssueInvoice<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeDisputeSol<'info> {
+    #[account(mut, has_one = produce)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(mut)]
+    pub produce: Account<'info, Produce>,
+    #[account(mut, seeds = [b"arbitrator_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ArbitratorRegistry>,
+    #[account(
+        mut,
+        seeds = [b"reputation", loser_reputation.owner.as_ref()],
+        bump = loser_reputation.bump,
+        constraint = loser_reputation.owner == dispute.loser(&produce) @ ErrorCode::InvalidReputationAccount
+    )]
+    pub loser_reputation: Account<'info, Reputation>,
+    #[account(mut, seeds = [b"sol_escrow", produce.key().as_ref()], bump = sol_escrow.bump)]
+    pub sol_escrow: Account<'info, SolEscrow>,
+    #[account(mut)]
+    pub farmer: SystemAccount<'info>,
+    #[account(mut)]
+    pub transporter: SystemAccount<'info>,
+    #[account(mut)]
+    pub buyer: SystemAccount<'info>,
+    /// Receives the dispute bond: the raiser if upheld, otherwise the counterparty.
+    #[account(mut)]
+    pub bond_recipient: SystemAccount<'info>,
+    /// Receives the appeal bond, if any: the appellant if the appeal succeeded.
+    #[account(mut)]
+    pub appeal_bond_recipient: SystemAccount<'info>,
+    /// Receives the response bond, if any: the raiser if upheld, otherwise the respondent.
+    #[account(mut)]
+    pub response_bond_recipient: SystemAccount<'info>,
+    #[account(seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    pub invoicing: IssueInvoice<'info>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(farmer_bps: u16, transporter_bps: u16, buyer_bps: u16) -> SettlementSplit {
+        SettlementSplit { farmer_bps, transporter_bps, buyer_bps }
+    }
+
+    fn funded_batch(escrowed_amount: u64) -> Produce {
+        let mut produce = Produce::deserialize(&mut &[0u8; 1024][..]).unwrap();
+        produce.escrowed_amount = escrowed_amount;
+        produce
+    }
+
+    #[test]
+    fn apply_divides_amount_by_shares() {
+        assert_eq!(split(5_000, 2_000, 3_000).apply(1_000).unwrap(), (500, 200, 300));
+        assert_eq!(split(10_000, 0, 0).apply(777).unwrap(), (777, 0, 0));
+        assert_eq!(split(0, 0, 10_000).apply(777).unwrap(), (0, 0, 777));
+    }
+
+    #[test]
+    fn apply_gives_rounding_dust_to_buyer() {
+        let (farmer, transporter, buyer) = split(3_333, 3_333, 3_334).apply(10).unwrap();
+        assert_eq!((farmer, transporter, buyer), (3, 3, 4));
+        let (farmer, transporter, buyer) = split(5_000, 5_000, 0).apply(3).unwrap();
+        assert_eq!((farmer, transporter, buyer), (1, 1, 1));
+    }
+
+    #[test]
+    fn apply_conserves_large_amounts() {
+        let (farmer, transporter, buyer) = split(4_000, 3_500, 2_500).apply(u64::MAX).unwrap();
+        assert_eq!(farmer as u128 + transporter as u128 + buyer as u128, u64::MAX as u128);
+    }
+
+    #[test]
+    fn apply_of_nothing_is_nothing() {
+        assert_eq!(split(4_000, 3_500, 2_500).apply(0).unwrap(), (0, 0, 0));
+    }
+
+    #[test]
+    fn validate_requires_shares_to_total_10000() {
+        assert!(split(4_000, 3_500, 2_500).validate().is_ok());
+        assert!(split(4_000, 3_500, 2_000).validate().is_err());
+        assert!(split(u16::MAX, 0, 0).validate().is_err());
+    }
+
+    #[test]
+    fn concurrent_disputes_each_decide_their_own_share() {
+        let mut produce = funded_batch(1_000);
+        let first = produce.hold_for_dispute();
+        let second = produce.hold_for_dispute();
+        assert_eq!((first, second), (1_000, 0));
+
+        // The later dispute settling first cannot move the funds the first one holds.
+        assert_eq!(produce.release_dispute_share(second), 0);
+        assert!(!produce.settled);
+        assert_eq!(produce.escrowed_amount, 1_000);
+
+        let released = produce.release_dispute_share(first);
+        assert_eq!(split(6_000, 0, 4_000).apply(released).unwrap(), (600, 0, 400));
+        assert!(produce.settled);
+        assert_eq!((produce.escrowed_amount, produce.disputed_amount), (0, 0));
+    }
+
+    #[test]
+    fn later_dispute_holds_what_remains() {
+        let mut produce = funded_batch(1_000);
+        let first = produce.hold_for_dispute();
+        // Deposited while the first dispute was open, so not held by it.
+        produce.escrowed_amount += 250;
+        assert_eq!(produce.release_dispute_share(first), 1_000);
+
+        let second = produce.hold_for_dispute();
+        assert_eq!(second, 250);
+        assert_eq!(produce.release_dispute_share(second), 250);
+        assert_eq!(produce.escrowed_amount, 0);
+    }
+
+    #[test]
+    fn dispute_share_is_limited_to_the_escrow_left() {
+        let mut produce = funded_batch(1_000);
+        let share = produce.hold_for_dispute();
+        // Inspection fees paid out of the escrow while the dispute is open.
+        produce.escrowed_amount -= 30;
+        assert_eq!(produce.release_dispute_share(share), 970);
+        assert_eq!((produce.escrowed_amount, produce.disputed_amount), (0, 0));
+    }
+
+    #[test]
+    fn timed_out_appeal_returns_the_appeal_bond_and_keeps_the_ruling() {
+        let mut produce = funded_batch(1_000);
+        produce.farmer = Pubkey::new_unique();
+        produce.buyer = Pubkey::new_unique();
+        let mut dispute = Dispute::deserialize(&mut &[0u8; 2048][..]).unwrap();
+        dispute.raiser = produce.buyer;
+        // Upheld for the buyer, appealed by the farmer, then nobody ruled.
+        dispute.appeal_round = 1;
+        dispute.appellant = produce.farmer;
+        dispute.appealed_resolution = false;
+        dispute.resolution = dispute.appealed_resolution;
+        dispute.timed_out = true;
+
+        assert!(dispute.adjudicated());
+        assert_eq!(dispute.appeal_bond_recipient(&produce), produce.farmer);
+        assert_eq!(dispute.bond_recipient(&produce), produce.buyer);
+        assert_eq!(dispute.response_bond_recipient(&produce), produce.buyer);
+    }
+
+    #[test]
+    fn unruled_dispute_returns_the_response_bond() {
+        let mut produce = funded_batch(1_000);
+        produce.farmer = Pubkey::new_unique();
+        produce.buyer = Pubkey::new_unique();
+        let mut dispute = Dispute::deserialize(&mut &[0u8; 2048][..]).unwrap();
+        dispute.raiser = produce.buyer;
+        dispute.timed_out = true;
+
+        assert!(!dispute.adjudicated());
+        assert_eq!(dispute.response_bond_recipient(&produce), produce.farmer);
+    }
+}
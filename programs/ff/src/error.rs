@@ -18,4 +18,100 @@ pub enum ErrorCode {
     InsufficientFunds,
     #[msg("Vote is already casted")]
     AlreadyVoted,
+    #[msg("Invalid configuration value.")]
+    InvalidConfig,
+    #[msg("Description exceeds maximum length of 128 characters.")]
+    DescriptionTooLong,
+    #[msg("Staked amount is below the required minimum.")]
+    InsufficientStake,
+    #[msg("Invalid proposal status for this operation.")]
+    InvalidProposalStatus,
+    #[msg("Voting period has ended.")]
+    VotingClosed,
+    #[msg("Voting period is still active.")]
+    VotingActive,
+    #[msg("Proposal timelock has not elapsed.")]
+    TimelockActive,
+    #[msg("Voting weight is delegated to another participant.")]
+    VotesDelegated,
+    #[msg("Delegate has voted with your weight on a proposal that is still open.")]
+    DelegateAlreadyVoted,
+    #[msg("Invalid delegate.")]
+    InvalidDelegate,
+    #[msg("Votes are locked until proposals you voted on close.")]
+    VoteLocked,
+    #[msg("Participant role is not eligible to vote on this proposal type.")]
+    IneligibleVoter,
+    #[msg("Proposal action does not match this instruction.")]
+    ProposalActionMismatch,
+    #[msg("Arbitrator is not active in the registry.")]
+    ArbitratorNotActive,
+    #[msg("Arbitrator registry is full.")]
+    RegistryFull,
+    #[msg("Settlement split must total 10000 basis points.")]
+    InvalidSplit,
+    #[msg("Payout account does not belong to the expected party.")]
+    InvalidPayoutAccount,
+    #[msg("URI exceeds maximum length of 128 characters.")]
+    UriTooLong,
+    #[msg("Evidence submission for this dispute is closed.")]
+    EvidenceClosed,
+    #[msg("Evidence period is still open.")]
+    EvidencePeriodOpen,
+    #[msg("Arbitrator is a party to this shipment.")]
+    ConflictOfInterest,
+    #[msg("This dispute is not decided by a panel.")]
+    PanelNotRequired,
+    #[msg("Arbitration panel already assigned.")]
+    PanelAlreadyAssigned,
+    #[msg("Arbitration panel has not been assigned yet.")]
+    PanelNotAssigned,
+    #[msg("Not enough eligible arbitrators for a panel.")]
+    NotEnoughArbitrators,
+    #[msg("This dispute can no longer be appealed.")]
+    AppealNotAllowed,
+    #[msg("Appeal window has closed.")]
+    AppealWindowClosed,
+    #[msg("Appeal window is still open.")]
+    AppealWindowOpen,
+    #[msg("Arbitrators still have time to rule.")]
+    RulingPeriodOpen,
+    #[msg("Reputation account does not belong to the expected party.")]
+    InvalidReputationAccount,
+    #[msg("Quality scores must be between 0 and 100.")]
+    InvalidQualityScore,
+    #[msg("This batch has reached the maximum number of inspections.")]
+    TooManyInspections,
+    #[msg("Mint is not on the settlement allowlist.")]
+    MintNotAllowed,
+    #[msg("Mint allowlist is full.")]
+    MintAllowlistFull,
+    #[msg("Token mint does not match the batch's settlement mint.")]
+    MintMismatch,
+    #[msg("Fee has already been claimed.")]
+    FeeAlreadyClaimed,
+    #[msg("Milestone shares must add up to 10000 basis points.")]
+    InvalidMilestoneSchedule,
+    #[msg("This milestone has already been released.")]
+    MilestoneAlreadyReleased,
+    #[msg("Received quantity exceeds the quantity shipped.")]
+    InvalidQuantity,
+    #[msg("Payments are deferred while a dispute is open.")]
+    PaymentsDeferred,
+    #[msg("The quality milestone is released by settling the batch.")]
+    SettlementMilestone,
+    #[msg("The batch does not qualify for this insurance claim.")]
+    NotEligibleForClaim,
+    #[msg("This policy has already paid a claim.")]
+    AlreadyClaimed,
+    #[msg("Invoice accounts do not belong to the batch being settled.")]
+    InvoiceAccountMismatch,
+    #[msg("The policy's claim window has closed.")]
+    ClaimWindowClosed,
+    #[msg("Underwriting withdrawal has not been requested or is still locked.")]
+    WithdrawalLocked,
+    #[msg("The insurance pool's shares are worth nothing until it recovers.")]
+    PoolDepleted,
+    #[msg("Stake is locked until the proposals it backs close voting.")]
+    StakeLocked,
 }
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::error::ErrorCode;
+use crate::participant::{Participant, ParticipantRole};
+use crate::payment::{compute_payouts, gross_up, Milestone, NATIVE_SOL};
+use crate::produce::Produce;
+
+/// A farmer's claim on the unreleased payouts of a batch, sold to a
+/// liquidity provider.
+#[account]
+pub struct Receivable {
+    pub produce: Pubkey,
+    pub farmer: Pubkey,
+    pub liquidity_provider: Pubkey,
+    pub purchase_price: u64,   // paid to the farmer up front
+    pub face_value: u64,       // unreleased farmer payout at the time of purchase, before adjustments
+    pub purchased_at: i64,
+    pub bump: u8,
+}
+
+impl Receivable {
+    // Size: produce (32) + farmer (32) + liquidity_provider (32) + purchase_price (8)
+    // + face_value (8) + purchased_at (8) + bump (1) = 121.
+    pub const LEN: usize = 121;
+}
+
+#[event]
+pub struct ReceivablePurchased {
+    pub produce: Pubkey,
+    pub farmer: Pubkey,
+    pub liquidity_provider: Pubkey,
+    pub purchase_price: u64,
+    pub face_value: u64,
+    pub timestamp: i64,
+}
+
+/// Sells the farmer's receivable on a picked-up, unsettled batch to a
+/// liquidity provider for `purchase_price`.
+pub fn purchase_receivable(ctx: Context<PurchaseReceivable>, purchase_price: u64) -> Result<()> {
+    require!(
+        matches!(ctx.accounts.liquidity_provider_account.role, ParticipantRole::LiquidityProvider),
+        ErrorCode::Unauthorized
+    );
+    let produce = &mut ctx.accounts.produce;
+    require!(
+        produce.pickup_confirmed
+            && !produce.dispute_raised
+            && !produce.settled
+            && produce.milestones_released & Milestone::Quality.mask() == 0,
+        ErrorCode::InvalidStatus
+    );
+    // Native SOL batches have no settlement mint to pay the farmer in.
+    require!(produce.settlement_mint != NATIVE_SOL, ErrorCode::MintMismatch);
+
+    let face_value = compute_payouts(produce)
+        .map(|(farmer_total, _)| farmer_total.saturating_sub(produce.farmer_released))
+        .unwrap_or(0);
+    let liquidity_provider = ctx.accounts.liquidity_provider.key();
+    produce.receivable_owner = liquidity_provider;
+
+    let now = Clock::get()?.unix_timestamp;
+    let receivable = &mut ctx.accounts.receivable;
+    receivable.produce = produce.key();
+    receivable.farmer = produce.farmer;
+    receivable.liquidity_provider = liquidity_provider;
+    receivable.purchase_price = purchase_price;
+    receivable.face_value = face_value;
+    receivable.purchased_at = now;
+    receivable.bump = ctx.bumps.receivable;
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.liquidity_provider_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.farmer_token_account.to_account_info(),
+        authority: ctx.accounts.liquidity_provider.to_account_info(),
+    };
+    // The liquidity provider covers any transfer fee so the farmer receives the full price.
+    token_interface::transfer_checked(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+        gross_up(&ctx.accounts.mint, purchase_price)?,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(ReceivablePurchased {
+        produce: receivable.produce,
+        farmer: receivable.farmer,
+        liquidity_provider,
+        purchase_price,
+        face_value,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PurchaseReceivable<'info> {
+    #[account(
+        mut,
+        seeds = [b"produce", &produce.produce_id.to_le_bytes()[..]],
+        bump,
+        has_one = farmer @ ErrorCode::Unauthorized
+    )]
+    pub produce: Account<'info, Produce>,
+    #[account(
+        init,
+        payer = liquidity_provider,
+        space = 8 + Receivable::LEN,
+        seeds = [b"receivable", produce.key().as_ref()],
+        bump
+    )]
+    pub receivable: Account<'info, Receivable>,
+    pub farmer: Signer<'info>,
+    #[account(mut)]
+    pub liquidity_provider: Signer<'info>,
+    #[account(
+        seeds = [b"participant", liquidity_provider.key().as_ref()],
+        bump,
+        constraint = liquidity_provider_account.owner == liquidity_provider.key()
+    )]
+    pub liquidity_provider_account: Account<'info, Participant>,
+    #[account(mut)]
+    pub liquidity_provider_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = farmer_token_account.owner == farmer.key() @ ErrorCode::InvalidPayoutAccount
+    )]
+    pub farmer_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = produce.settlement_mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
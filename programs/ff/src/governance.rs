@@ -1,20 +1,198 @@
 use anchor_lang::prelude::*;
+use crate::arbitrator::ArbitratorRegistry;
 use crate::error::ErrorCode;
+use crate::participant::{Participant, ParticipantRole, ROLE_COUNT};
+use crate::payment::StakeAccount;
+
+/// Lifecycle of a governance proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Rejected,
+    Queued,
+    Executed,
+    Cancelled,
+}
+
+/// Category of a proposal; each category has its own electorate.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalType {
+    General,
+    PricingPolicy,
+    DisputeRules,
+}
+
+pub const PROPOSAL_TYPE_COUNT: usize = 3;
+
+impl ProposalType {
+    pub fn index(&self) -> usize {
+        match self {
+            ProposalType::General => 0,
+            ProposalType::PricingPolicy => 1,
+            ProposalType::DisputeRules => 2,
+        }
+    }
+}
+
+/// Global governance parameters and the proposal counter.
+#[account]
+pub struct GovernanceConfig {
+    pub admin: Pubkey,
+    pub voting_period: i64,      // seconds a proposal stays open for voting
+    pub quorum: u64,             // minimum voting weight cast (for, against and abstain) for a valid result
+    pub min_proposer_stake: u64, // tokens the proposer must have staked
+    pub timelock: i64,           // seconds between queueing and execution
+    pub proposal_count: u64,     // next proposal id
+    pub bump: u8,
+    pub role_weights: [u16; ROLE_COUNT],              // voting weight per participant role
+    pub eligible_roles: [u8; PROPOSAL_TYPE_COUNT],    // role bitmask allowed to vote, per proposal type
+}
+
+impl GovernanceConfig {
+    // Size: admin (32) + voting_period (8) + quorum (8) + min_proposer_stake (8) + timelock (8) + proposal_count (8) + bump (1)
+    // + role_weights (16) + eligible_roles (3) = 92.
+    pub const LEN: usize = 92;
+
+    /// Voting weight the participant carries on a proposal of the given type:
+    /// their own role weight (if eligible) plus eligible delegated weight.
+    pub fn voting_weight(
+        &self,
+        participant: &Participant,
+        proposal_type: ProposalType,
+        registry: &ArbitratorRegistry,
+    ) -> Result<u64> {
+        let eligible = self.eligible_roles[proposal_type.index()];
+        let arbitrator = ParticipantRole::Arbitrator.index();
+        let mut weight: u64 = 0;
+        for role in 0..ROLE_COUNT {
+            if eligible & (1 << role) == 0 {
+                continue;
+            }
+            let mut count = if role == arbitrator {
+                0
+            } else {
+                participant.delegated_by_role[role] as u64
+            };
+            if participant.role.index() == role
+                && (role != arbitrator || registry.is_active(&participant.owner))
+            {
+                count = count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+            }
+            let role_weight = count
+                .checked_mul(self.role_weights[role] as u64)
+                .ok_or(ErrorCode::Overflow)?;
+            weight = weight.checked_add(role_weight).ok_or(ErrorCode::Overflow)?;
+        }
+        Ok(weight)
+    }
+}
+
+/// On-chain effect applied when a proposal is executed. `None` is a signalling
+/// proposal; other actions are executed by the instruction that owns the
+/// affected state.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ProposalAction {
+    None,
+    AppointArbitrator { arbitrator: Pubkey },
+    SuspendArbitrator { arbitrator: Pubkey },
+    CertifyInspector { inspector: Pubkey },
+    RevokeInspector { inspector: Pubkey },
+    AllowMint { mint: Pubkey },
+    DisallowMint { mint: Pubkey },
+    TreasurySpend { mint: Pubkey, recipient: Pubkey, amount: u64 }, // mint is NATIVE_SOL for lamports
+}
+
+impl ProposalAction {
+    // Size: variant (1) + largest payload (32 + 32 + 8) = 73.
+    pub const LEN: usize = 73;
+}
+
+/// A voter's choice on a proposal. Abstentions count toward quorum only.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum VoteChoice {
+    For,
+    Against,
+    Abstain,
+}
 
 #[account]
 pub struct GovernanceProposal {
     pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub proposal_type: ProposalType,
+    pub action: ProposalAction,
     pub description: String,
     pub votes_for: u64,
     pub votes_against: u64,
-    pub executed: bool,
+    pub votes_abstain: u64,
+    pub status: ProposalStatus,
     pub created_at: i64,
-    pub voters: Vec<Pubkey>, // Add this
+    pub voting_ends_at: i64,
+    pub executable_at: i64, // set when the proposal is queued
 }
 
 impl GovernanceProposal {
-    // Size: proposal_id (8) + description (4+128) + votes_for (8) + votes_against (8) + executed (1) + created_at (8) = ~165, plus 8 = 173.
-    pub const LEN: usize = 1024;
+    // Size: proposal_id (8) + proposer (32) + proposal_type (1) + action (73) + description (4+128) + votes_for (8)
+    // + votes_against (8) + votes_abstain (8) + status (1) + timestamps (24) = 295.
+    pub const LEN: usize = 295;
+
+    /// Marks a queued proposal as executed once its timelock has elapsed.
+    pub fn begin_execution(&mut self) -> Result<()> {
+        require!(self.status != ProposalStatus::Executed, ErrorCode::AlreadyExecuted);
+        require!(self.status == ProposalStatus::Queued, ErrorCode::InvalidProposalStatus);
+        require!(
+            Clock::get()?.unix_timestamp >= self.executable_at,
+            ErrorCode::TimelockActive
+        );
+        self.status = ProposalStatus::Executed;
+        Ok(())
+    }
+
+    fn tally_mut(&mut self, choice: VoteChoice) -> &mut u64 {
+        match choice {
+            VoteChoice::For => &mut self.votes_for,
+            VoteChoice::Against => &mut self.votes_against,
+            VoteChoice::Abstain => &mut self.votes_abstain,
+        }
+    }
+
+    fn add_votes(&mut self, choice: VoteChoice, weight: u64) -> Result<()> {
+        let tally = self.tally_mut(choice);
+        *tally = tally.checked_add(weight).ok_or(ErrorCode::Overflow)?;
+        Ok(())
+    }
+
+    fn remove_votes(&mut self, choice: VoteChoice, weight: u64) -> Result<()> {
+        let tally = self.tally_mut(choice);
+        *tally = tally.checked_sub(weight).ok_or(ErrorCode::Overflow)?;
+        Ok(())
+    }
+
+    fn require_voting_open(&self) -> Result<()> {
+        require!(self.status == ProposalStatus::Active, ErrorCode::InvalidProposalStatus);
+        require!(
+            Clock::get()?.unix_timestamp < self.voting_ends_at,
+            ErrorCode::VotingClosed
+        );
+        Ok(())
+    }
+}
+
+/// A single voter's ballot on a proposal.
+#[account]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub choice: VoteChoice,
+    pub weight: u64,
+    pub voted_at: i64, // time the ballot was first cast
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    // Size: proposal (32) + voter (32) + choice (1) + weight (8) + voted_at (8) + bump (1) = 82.
+    pub const LEN: usize = 82;
 }
 
 #[event]
@@ -24,18 +202,140 @@ pub struct ProposalCreated {
     pub timestamp: i64,
 }
 
-/// Creates a new governance proposal.
-pub fn create_proposal(ctx: Context<CreateProposal>, proposal_id: u64, description: String) -> Result<()> {
+#[event]
+pub struct ProposalFinalized {
+    pub proposal_id: u64,
+    pub passed: bool,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub votes_abstain: u64,
+}
+
+/// Initializes the governance configuration. The signer becomes the admin.
+pub fn initialize_governance(
+    ctx: Context<InitializeGovernance>,
+    voting_period: i64,
+    quorum: u64,
+    min_proposer_stake: u64,
+    timelock: i64,
+) -> Result<()> {
+    require!(voting_period > 0 && timelock >= 0, ErrorCode::InvalidConfig);
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.voting_period = voting_period;
+    config.quorum = quorum;
+    config.min_proposer_stake = min_proposer_stake;
+    config.timelock = timelock;
+    config.proposal_count = 0;
+    config.bump = ctx.bumps.config;
+    config.role_weights = [1; ROLE_COUNT];
+    // Defaults: everyone votes on general proposals, farmers and retailers on
+    // pricing policy, arbitrators on dispute rules.
+    config.eligible_roles[ProposalType::General.index()] = u8::MAX;
+    config.eligible_roles[ProposalType::PricingPolicy.index()] =
+        ParticipantRole::Farmer.mask() | ParticipantRole::Retailer.mask();
+    config.eligible_roles[ProposalType::DisputeRules.index()] = ParticipantRole::Arbitrator.mask();
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + GovernanceConfig::LEN,
+        seeds = [b"governance_config"],
+        bump
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Updates the governance parameters. Admin only.
+pub fn update_governance_config(
+    ctx: Context<UpdateGovernanceConfig>,
+    voting_period: i64,
+    quorum: u64,
+    min_proposer_stake: u64,
+    timelock: i64,
+) -> Result<()> {
+    require!(voting_period > 0 && timelock >= 0, ErrorCode::InvalidConfig);
+    let config = &mut ctx.accounts.config;
+    config.voting_period = voting_period;
+    config.quorum = quorum;
+    config.min_proposer_stake = min_proposer_stake;
+    config.timelock = timelock;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateGovernanceConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    pub admin: Signer<'info>,
+}
+
+/// Sets per-role voting weights and the roles allowed to vote on each proposal type. Admin only.
+pub fn set_governance_electorate(
+    ctx: Context<UpdateGovernanceConfig>,
+    role_weights: [u16; ROLE_COUNT],
+    eligible_roles: [u8; PROPOSAL_TYPE_COUNT],
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.role_weights = role_weights;
+    config.eligible_roles = eligible_roles;
+    Ok(())
+}
+
+/// Creates a new governance proposal under the next id from the counter.
+/// The proposer must be a registered participant eligible to vote on the proposal type,
+/// and their minimum stake is locked until voting ends.
+pub fn create_proposal(
+    ctx: Context<CreateProposal>,
+    proposal_type: ProposalType,
+    action: ProposalAction,
+    description: String,
+) -> Result<()> {
+    require!(description.len() <= 128, ErrorCode::DescriptionTooLong);
+    let config = &mut ctx.accounts.config;
+    require!(
+        ctx.accounts.proposer_stake.amount >= config.min_proposer_stake,
+        ErrorCode::InsufficientStake
+    );
+    require!(
+        config.eligible_roles[proposal_type.index()] & ctx.accounts.proposer_account.role.mask() != 0,
+        ErrorCode::IneligibleVoter
+    );
+
+    let now = Clock::get()?.unix_timestamp;
     let proposal = &mut ctx.accounts.proposal;
-    proposal.proposal_id = proposal_id;
+    proposal.proposal_id = config.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.proposal_type = proposal_type;
+    proposal.action = action;
     proposal.description = description;
     proposal.votes_for = 0;
     proposal.votes_against = 0;
-    proposal.executed = false;
-    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.votes_abstain = 0;
+    proposal.status = ProposalStatus::Active;
+    proposal.created_at = now;
+    proposal.voting_ends_at = now.checked_add(config.voting_period).ok_or(ErrorCode::Overflow)?;
+    proposal.executable_at = 0;
+    // The backing stake stays in the vault until voting ends.
+    ctx.accounts
+        .proposer_stake
+        .lock(config.min_proposer_stake, proposal.voting_ends_at, now);
+    config.proposal_count = config.proposal_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
 
     emit!(ProposalCreated {
-        proposal_id,
+        proposal_id: proposal.proposal_id,
         description: proposal.description.clone(),
         timestamp: proposal.created_at,
     });
@@ -43,51 +343,340 @@ pub fn create_proposal(ctx: Context<CreateProposal>, proposal_id: u64, descripti
 }
 
 #[derive(Accounts)]
-#[instruction(proposal_id: u64)]
 pub struct CreateProposal<'info> {
+    #[account(mut, seeds = [b"governance_config"], bump = config.bump)]
+    pub config: Account<'info, GovernanceConfig>,
     #[account(
         init,
         payer = proposer,
         space = 8 + GovernanceProposal::LEN,
-        seeds = [b"proposal", &proposal_id.to_le_bytes()],
+        seeds = [b"proposal", &config.proposal_count.to_le_bytes()],
         bump
     )]
     pub proposal: Account<'info, GovernanceProposal>,
+    #[account(
+        mut,
+        seeds = [b"stake", proposer.key().as_ref()],
+        bump = proposer_stake.bump,
+        constraint = proposer_stake.owner == proposer.key() @ ErrorCode::Unauthorized
+    )]
+    pub proposer_stake: Account<'info, StakeAccount>,
+    #[account(
+        seeds = [b"participant", proposer.key.as_ref()],
+        bump,
+        constraint = proposer_account.owner == proposer.key()
+    )]
+    pub proposer_account: Account<'info, Participant>,
     #[account(mut)]
     pub proposer: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// Votes on a proposal.
-pub fn vote_proposal(ctx: Context<VoteProposal>, _proposal_id: u64, vote_for: bool) -> Result<()> {
+/// Votes on a proposal while its voting window is open. The voter's weight is
+/// their own role weight plus any weight delegated to them, counting only roles
+/// eligible for the proposal type.
+pub fn vote_proposal(ctx: Context<VoteProposal>, _proposal_id: u64, choice: VoteChoice) -> Result<()> {
     let proposal = &mut ctx.accounts.proposal;
-    let voter = ctx.accounts.voter.key();
-    require!(!proposal.voters.contains(&voter), ErrorCode::AlreadyVoted);
-    if vote_for {
-        proposal.votes_for = proposal.votes_for.checked_add(1).ok_or(ErrorCode::Overflow)?;
-    } else {
-        proposal.votes_against = proposal.votes_against.checked_add(1).ok_or(ErrorCode::Overflow)?;
-    }
-    proposal.voters.push(voter);
+    proposal.require_voting_open()?;
+
+    let voter_account = &mut ctx.accounts.voter_account;
+    // Delegated weight cannot come back while a proposal the delegate voted on
+    // is open (see `undelegate_votes`), so it is never cast twice.
+    require!(voter_account.delegate == Pubkey::default(), ErrorCode::VotesDelegated);
+
+    let weight = ctx
+        .accounts
+        .config
+        .voting_weight(voter_account, proposal.proposal_type, &ctx.accounts.registry)?;
+    require!(weight > 0, ErrorCode::IneligibleVoter);
+    proposal.add_votes(choice, weight)?;
+    voter_account.vote_locked_until = voter_account.vote_locked_until.max(proposal.voting_ends_at);
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = proposal.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.choice = choice;
+    vote_record.weight = weight;
+    vote_record.voted_at = Clock::get()?.unix_timestamp;
+    vote_record.bump = ctx.bumps.vote_record;
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct VoteProposal<'info> {
+    #[account(seeds = [b"governance_config"], bump = config.bump)]
+    pub config: Account<'info, GovernanceConfig>,
     #[account(mut, seeds = [b"proposal", &proposal.proposal_id.to_le_bytes()], bump)]
     pub proposal: Account<'info, GovernanceProposal>,
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::LEN,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key.as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    #[account(
+        mut,
+        seeds = [b"participant", voter.key.as_ref()],
+        bump,
+        constraint = voter_account.owner == voter.key()
+    )]
+    pub voter_account: Account<'info, Participant>,
+    #[account(seeds = [b"arbitrator_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ArbitratorRegistry>,
     #[account(mut)]
     pub voter: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// Executes a proposal if conditions are met.
-pub fn execute_proposal(ctx: Context<ExecuteProposal>, _proposal_id: u64) -> Result<()> {
+/// Changes an existing ballot before the voting window closes. The weight
+/// recorded when the ballot was cast moves to the new choice.
+pub fn change_vote(ctx: Context<ChangeVote>, choice: VoteChoice) -> Result<()> {
     let proposal = &mut ctx.accounts.proposal;
-    require!(!proposal.executed, ErrorCode::AlreadyExecuted);
-    proposal.executed = true;
+    proposal.require_voting_open()?;
+    let vote_record = &mut ctx.accounts.vote_record;
+    proposal.remove_votes(vote_record.choice, vote_record.weight)?;
+    proposal.add_votes(choice, vote_record.weight)?;
+    vote_record.choice = choice;
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    #[account(mut, seeds = [b"proposal", &proposal.proposal_id.to_le_bytes()], bump)]
+    pub proposal: Account<'info, GovernanceProposal>,
+    #[account(
+        mut,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key.as_ref()],
+        bump = vote_record.bump,
+        has_one = voter @ ErrorCode::Unauthorized
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    pub voter: Signer<'info>,
+}
+
+/// Withdraws a ballot before the voting window closes and closes its record.
+pub fn withdraw_vote(ctx: Context<WithdrawVote>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.require_voting_open()?;
+    let vote_record = &ctx.accounts.vote_record;
+    proposal.remove_votes(vote_record.choice, vote_record.weight)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawVote<'info> {
+    #[account(mut, seeds = [b"proposal", &proposal.proposal_id.to_le_bytes()], bump)]
+    pub proposal: Account<'info, GovernanceProposal>,
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key.as_ref()],
+        bump = vote_record.bump,
+        has_one = voter @ ErrorCode::Unauthorized
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+}
+
+#[event]
+pub struct VotesDelegated {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VotesUndelegated {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Assigns the delegator's governance weight to another participant.
+pub fn delegate_votes(ctx: Context<DelegateVotes>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let delegator_account = &mut ctx.accounts.delegator_account;
+    let delegate_account = &mut ctx.accounts.delegate_account;
+    require!(delegate_account.owner != delegator_account.owner, ErrorCode::InvalidDelegate);
+    require!(delegator_account.delegate == Pubkey::default(), ErrorCode::VotesDelegated);
+    // No delegation chains: a delegate must vote directly, and a participant
+    // holding delegations cannot pass them on.
+    require!(delegate_account.delegate == Pubkey::default(), ErrorCode::InvalidDelegate);
+    require!(
+        delegator_account.delegated_by_role.iter().all(|count| *count == 0),
+        ErrorCode::InvalidDelegate
+    );
+    // Weight already cast on an open proposal must not be counted again by the delegate.
+    require!(now >= delegator_account.vote_locked_until, ErrorCode::VoteLocked);
+
+    delegator_account.delegate = delegate_account.owner;
+    let role = delegator_account.role.index();
+    delegate_account.delegated_by_role[role] = delegate_account.delegated_by_role[role]
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit!(VotesDelegated {
+        delegator: delegator_account.owner,
+        delegate: delegate_account.owner,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DelegateVotes<'info> {
+    #[account(
+        mut,
+        seeds = [b"participant", delegator.key.as_ref()],
+        bump,
+        constraint = delegator_account.owner == delegator.key()
+    )]
+    pub delegator_account: Account<'info, Participant>,
+    #[account(
+        mut,
+        seeds = [b"participant", delegate_account.owner.as_ref()],
+        bump
+    )]
+    pub delegate_account: Account<'info, Participant>,
+    pub delegator: Signer<'info>,
+}
+
+/// Takes back delegated governance weight once every proposal the delegate
+/// voted on has closed.
+pub fn undelegate_votes(ctx: Context<UndelegateVotes>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let delegator_account = &mut ctx.accounts.delegator_account;
+    let delegate_account = &mut ctx.accounts.delegate_account;
+    require!(
+        delegator_account.delegate == delegate_account.owner,
+        ErrorCode::InvalidDelegate
+    );
+    require!(now >= delegate_account.vote_locked_until, ErrorCode::DelegateAlreadyVoted);
+
+    let role = delegator_account.role.index();
+    delegate_account.delegated_by_role[role] = delegate_account.delegated_by_role[role]
+        .checked_sub(1)
+        .ok_or(ErrorCode::Overflow)?;
+    delegator_account.delegate = Pubkey::default();
+
+    emit!(VotesUndelegated {
+        delegator: delegator_account.owner,
+        delegate: delegate_account.owner,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UndelegateVotes<'info> {
+    #[account(
+        mut,
+        seeds = [b"participant", delegator.key.as_ref()],
+        bump,
+        constraint = delegator_account.owner == delegator.key()
+    )]
+    pub delegator_account: Account<'info, Participant>,
+    #[account(
+        mut,
+        seeds = [b"participant", delegate_account.owner.as_ref()],
+        bump
+    )]
+    pub delegate_account: Account<'info, Participant>,
+    pub delegator: Signer<'info>,
+}
+
+/// Cancels a proposal. Only the proposer may cancel, and only before voting ends.
+pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.require_voting_open()?;
+    proposal.status = ProposalStatus::Cancelled;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"proposal", &proposal.proposal_id.to_le_bytes()],
+        bump,
+        has_one = proposer @ ErrorCode::Unauthorized
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+    pub proposer: Signer<'info>,
+}
+
+/// Tallies a proposal once voting has ended. Anyone may call this.
+pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(proposal.status == ProposalStatus::Active, ErrorCode::InvalidProposalStatus);
+    require!(
+        Clock::get()?.unix_timestamp >= proposal.voting_ends_at,
+        ErrorCode::VotingActive
+    );
+
+    let total_votes = proposal
+        .votes_for
+        .checked_add(proposal.votes_against)
+        .and_then(|total| total.checked_add(proposal.votes_abstain))
+        .ok_or(ErrorCode::Overflow)?;
+    let passed = total_votes >= config.quorum && proposal.votes_for > proposal.votes_against;
+    proposal.status = if passed {
+        ProposalStatus::Passed
+    } else {
+        ProposalStatus::Rejected
+    };
+
+    emit!(ProposalFinalized {
+        proposal_id: proposal.proposal_id,
+        passed,
+        votes_for: proposal.votes_for,
+        votes_against: proposal.votes_against,
+        votes_abstain: proposal.votes_abstain,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    #[account(seeds = [b"governance_config"], bump = config.bump)]
+    pub config: Account<'info, GovernanceConfig>,
+    #[account(mut, seeds = [b"proposal", &proposal.proposal_id.to_le_bytes()], bump)]
+    pub proposal: Account<'info, GovernanceProposal>,
+}
+
+/// Queues a passed proposal, starting the timelock. Anyone may call this.
+pub fn queue_proposal(ctx: Context<QueueProposal>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(proposal.status == ProposalStatus::Passed, ErrorCode::InvalidProposalStatus);
+    proposal.executable_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(config.timelock)
+        .ok_or(ErrorCode::Overflow)?;
+    proposal.status = ProposalStatus::Queued;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct QueueProposal<'info> {
+    #[account(seeds = [b"governance_config"], bump = config.bump)]
+    pub config: Account<'info, GovernanceConfig>,
+    #[account(mut, seeds = [b"proposal", &proposal.proposal_id.to_le_bytes()], bump)]
+    pub proposal: Account<'info, GovernanceProposal>,
+}
+
+/// Executes a queued signalling proposal once its timelock has elapsed.
+/// Proposals carrying an action are executed by that action's instruction.
+pub fn execute_proposal(ctx: Context<ExecuteProposal>, _proposal_id: u64) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    require!(proposal.action == ProposalAction::None, ErrorCode::ProposalActionMismatch);
+    proposal.begin_execution()
+}
+
 #[derive(Accounts)]
 pub struct ExecuteProposal<'info> {
     #[account(mut, seeds = [b"proposal", &proposal.proposal_id.to_le_bytes()], bump)]
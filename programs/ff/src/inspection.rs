@@ -0,0 +1,636 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::error::ErrorCode;
+use crate::governance::{GovernanceConfig, GovernanceProposal, ProposalAction};
+use crate::participant::{Participant, ParticipantRole};
+use crate::payment::{transfer_from_vault, transfer_lamports, SolEscrow, Vault};
+use crate::produce::{Produce, ProduceStatus};
+use crate::reputation::Reputation;
+
+/// Maximum number of inspections recorded per batch. The last slot is kept
+/// for a certified inspection.
+pub const MAX_INSPECTIONS: usize = 8;
+/// Quality scores and grades are on a 0-100 scale.
+pub const MAX_QUALITY_SCORE: u8 = 100;
+
+/// Component grades behind an inspection score.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct QualityGrades {
+    pub size: u8,
+    pub color: u8,
+    pub defects: u8, // higher is better: 100 means no visible defects
+}
+
+impl QualityGrades {
+    // Size: size (1) + color (1) + defects (1) = 3.
+    pub const LEN: usize = 3;
+
+    fn validate(&self) -> Result<()> {
+        require!(
+            self.size <= MAX_QUALITY_SCORE
+                && self.color <= MAX_QUALITY_SCORE
+                && self.defects <= MAX_QUALITY_SCORE,
+            ErrorCode::InvalidQualityScore
+        );
+        Ok(())
+    }
+}
+
+/// Program-wide inspection rules, set by the governance admin.
+#[account]
+pub struct QualityConfig {
+    pub certified_threshold: u64, // batches worth at least this much (price plus freight) need a certified inspection
+    pub min_quality: u8,          // effective quality below this flags the batch
+    pub bump: u8,
+    pub inspection_fee: u64,      // paid from the batch escrow to Inspector-role participants
+}
+
+impl QualityConfig {
+    // Size: certified_threshold (8) + min_quality (1) + bump (1) + inspection_fee (8) = 18.
+    pub const LEN: usize = 18;
+
+    /// Grades an inspected batch by its effective quality, unless it still
+    /// awaits a certified inspection.
+    pub fn grade(&self, produce: &mut Produce) {
+        let value = produce.farmer_price.saturating_add(produce.transporter_fee);
+        let awaiting_certified = value >= self.certified_threshold && produce.certified_inspections == 0;
+        if produce.inspection_scores.is_empty() || awaiting_certified {
+            return;
+        }
+        let failed = produce.verified_quality < self.min_quality;
+        produce.dispute_raised = failed || produce.open_disputes > 0;
+        if produce.delivery_confirmed {
+            produce.status = if failed {
+                ProduceStatus::Disputed
+            } else {
+                ProduceStatus::QualityVerified
+            };
+        }
+    }
+}
+
+/// Marks a participant as a certified inspector. Granted by the governance
+/// admin or by an executed governance proposal.
+#[account]
+pub struct InspectorCertification {
+    pub inspector: Pubkey,
+    pub certified_by: Pubkey,     // admin, or the proposal that certified the inspector
+    pub certified_at: i64,
+    pub active: bool,
+    pub bump: u8,
+    pub fees_earned: u64,
+    pub fees_unclaimed: u64,      // held in batch escrows until claimed
+}
+
+impl InspectorCertification {
+    // Size: inspector (32) + certified_by (32) + certified_at (8) + active (1) + bump (1)
+    // + fees_earned (8) + fees_unclaimed (8) = 90.
+    pub const LEN: usize = 90;
+
+    fn certify(&mut self, inspector: Pubkey, certified_by: Pubkey, now: i64, bump: u8) {
+        self.inspector = inspector;
+        self.certified_by = certified_by;
+        self.certified_at = now;
+        self.active = true;
+        self.bump = bump;
+    }
+}
+
+/// One inspector's assessment of a batch. Each inspector may inspect a batch
+/// once, so earlier results cannot be overwritten.
+#[account]
+pub struct QualityInspection {
+    pub produce: Pubkey,
+    pub inspector: Pubkey,
+    pub score: u8,
+    pub grades: QualityGrades,
+    pub lab_report_hash: [u8; 32], // zeroed when no lab report was filed
+    pub certified: bool,           // certified inspector independent of the shipment
+    pub inspected_at: i64,
+    pub bump: u8,
+    pub independent: bool,         // by a certified Inspector-role participant; takes precedence
+    pub fee: u64,                  // inspection fee charged to the batch escrow
+    pub fee_claimed: bool,
+}
+
+impl QualityInspection {
+    // Size: produce (32) + inspector (32) + score (1) + grades (3) + lab_report_hash (32)
+    // + certified (1) + inspected_at (8) + bump (1) + independent (1) + fee (8) + fee_claimed (1) = 120.
+    pub const LEN: usize = 32 + 32 + 1 + QualityGrades::LEN + 32 + 1 + 8 + 1 + 1 + 8 + 1;
+}
+
+/// Median of the given inspection scores; the mean of the two middle
+/// scores when the count is even.
+pub fn median_score(scores: &[u8]) -> u8 {
+    if scores.is_empty() {
+        return 0;
+    }
+    let mut sorted = scores.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    if sorted.len() & 1 == 1 {
+        sorted[mid]
+    } else {
+        ((sorted[mid - 1] as u16 + sorted[mid] as u16) / 2) as u8
+    }
+}
+
+#[event]
+pub struct QualityInspected {
+    pub produce: Pubkey,
+    pub inspector: Pubkey,
+    pub score: u8,
+    pub certified: bool,
+    pub effective_quality: u8,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InspectionFeesClaimed {
+    pub inspector: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InspectorCertificationChanged {
+    pub inspector: Pubkey,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+/// Creates the inspection configuration. Governance admin only.
+pub fn initialize_quality_config(
+    ctx: Context<InitializeQualityConfig>,
+    certified_threshold: u64,
+    min_quality: u8,
+    inspection_fee: u64,
+) -> Result<()> {
+    require!(min_quality <= MAX_QUALITY_SCORE, ErrorCode::InvalidConfig);
+    let quality_config = &mut ctx.accounts.quality_config;
+    quality_config.certified_threshold = certified_threshold;
+    quality_config.min_quality = min_quality;
+    quality_config.inspection_fee = inspection_fee;
+    quality_config.bump = ctx.bumps.quality_config;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeQualityConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + QualityConfig::LEN,
+        seeds = [b"quality_config"],
+        bump
+    )]
+    pub quality_config: Account<'info, QualityConfig>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Updates the inspection configuration. Governance admin only.
+pub fn update_quality_config(
+    ctx: Context<UpdateQualityConfig>,
+    certified_threshold: u64,
+    min_quality: u8,
+    inspection_fee: u64,
+) -> Result<()> {
+    require!(min_quality <= MAX_QUALITY_SCORE, ErrorCode::InvalidConfig);
+    let quality_config = &mut ctx.accounts.quality_config;
+    quality_config.certified_threshold = certified_threshold;
+    quality_config.min_quality = min_quality;
+    quality_config.inspection_fee = inspection_fee;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateQualityConfig<'info> {
+    #[account(mut, seeds = [b"quality_config"], bump = quality_config.bump)]
+    pub quality_config: Account<'info, QualityConfig>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    pub admin: Signer<'info>,
+}
+
+/// Certifies (or re-certifies) a participant as an inspector. Governance admin only.
+pub fn certify_inspector(ctx: Context<CertifyInspector>, inspector: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.certification.certify(
+        inspector,
+        ctx.accounts.admin.key(),
+        now,
+        ctx.bumps.certification,
+    );
+
+    emit!(InspectorCertificationChanged {
+        inspector,
+        active: true,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(inspector: Pubkey)]
+pub struct CertifyInspector<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + InspectorCertification::LEN,
+        seeds = [b"inspector_cert", inspector.as_ref()],
+        bump
+    )]
+    pub certification: Account<'info, InspectorCertification>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Revokes an inspector's certification. Governance admin only.
+pub fn revoke_inspector(ctx: Context<RevokeInspector>) -> Result<()> {
+    let certification = &mut ctx.accounts.certification;
+    certification.active = false;
+
+    emit!(InspectorCertificationChanged {
+        inspector: certification.inspector,
+        active: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeInspector<'info> {
+    #[account(
+        mut,
+        seeds = [b"inspector_cert", certification.inspector.as_ref()],
+        bump = certification.bump
+    )]
+    pub certification: Account<'info, InspectorCertification>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    pub admin: Signer<'info>,
+}
+
+/// Applies a queued inspector certification or revocation proposal once
+/// its timelock has elapsed. Anyone may call this; the executor pays for a
+/// new certification account.
+pub fn execute_inspector_proposal(ctx: Context<ExecuteInspectorProposal>, inspector: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    let certification = &mut ctx.accounts.certification;
+    let active = match proposal.action {
+        ProposalAction::CertifyInspector { inspector: target } if target == inspector => {
+            certification.certify(inspector, proposal.key(), now, ctx.bumps.certification);
+            true
+        }
+        ProposalAction::RevokeInspector { inspector: target } if target == inspector => {
+            require!(certification.inspector == inspector, ErrorCode::ProposalActionMismatch);
+            certification.active = false;
+            false
+        }
+        _ => return Err(ErrorCode::ProposalActionMismatch.into()),
+    };
+    proposal.begin_execution()?;
+
+    emit!(InspectorCertificationChanged {
+        inspector,
+        active,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(inspector: Pubkey)]
+pub struct ExecuteInspectorProposal<'info> {
+    #[account(mut, seeds = [b"proposal", &proposal.proposal_id.to_le_bytes()], bump)]
+    pub proposal: Account<'info, GovernanceProposal>,
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + InspectorCertification::LEN,
+        seeds = [b"inspector_cert", inspector.as_ref()],
+        bump
+    )]
+    pub certification: Account<'info, InspectorCertification>,
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Pays out the fee earned by an inspection from the batch escrow.
+pub fn claim_inspection_fees(ctx: Context<ClaimInspectionFees>) -> Result<()> {
+    let amount = take_inspection_fee(&mut ctx.accounts.inspection, &mut ctx.accounts.certification)?;
+    let accounts = &ctx.accounts;
+    transfer_from_vault(
+        &accounts.token_program,
+        &accounts.escrow,
+        &accounts.inspector_token_account,
+        &accounts.mint,
+        &accounts.vault,
+        amount,
+    )
+}
+
+/// Native SOL counterpart of `claim_inspection_fees`.
+pub fn claim_inspection_fees_sol(ctx: Context<ClaimInspectionFeesSol>) -> Result<()> {
+    let amount = take_inspection_fee(&mut ctx.accounts.inspection, &mut ctx.accounts.certification)?;
+    transfer_lamports(
+        &ctx.accounts.sol_escrow.to_account_info(),
+        &ctx.accounts.inspector.to_account_info(),
+        amount,
+    )
+}
+
+/// Marks an inspection's fee claimed and returns the amount owed.
+fn take_inspection_fee(
+    inspection: &mut QualityInspection,
+    certification: &mut InspectorCertification,
+) -> Result<u64> {
+    require!(!inspection.fee_claimed, ErrorCode::FeeAlreadyClaimed);
+    inspection.fee_claimed = true;
+    let amount = inspection.fee;
+    certification.fees_unclaimed = certification.fees_unclaimed.saturating_sub(amount);
+
+    emit!(InspectionFeesClaimed {
+        inspector: inspection.inspector,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(amount)
+}
+
+#[derive(Accounts)]
+pub struct ClaimInspectionFees<'info> {
+    #[account(mut, seeds = [b"inspector_cert", inspector.key.as_ref()], bump = certification.bump)]
+    pub certification: Account<'info, InspectorCertification>,
+    #[account(
+        mut,
+        has_one = produce,
+        has_one = inspector @ ErrorCode::Unauthorized
+    )]
+    pub inspection: Account<'info, QualityInspection>,
+    pub produce: Account<'info, Produce>,
+    pub inspector: Signer<'info>,
+    #[account(
+        mut,
+        constraint = inspector_token_account.owner == inspector.key() @ ErrorCode::InvalidPayoutAccount
+    )]
+    pub inspector_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"vault"], bump = vault.bump)]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"escrow", produce.key().as_ref(), produce.settlement_mint.as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = produce.settlement_mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimInspectionFeesSol<'info> {
+    #[account(mut, seeds = [b"inspector_cert", inspector.key.as_ref()], bump = certification.bump)]
+    pub certification: Account<'info, InspectorCertification>,
+    #[account(
+        mut,
+        has_one = produce,
+        has_one = inspector @ ErrorCode::Unauthorized
+    )]
+    pub inspection: Account<'info, QualityInspection>,
+    pub produce: Account<'info, Produce>,
+    #[account(mut)]
+    pub inspector: Signer<'info>,
+    #[account(mut, seeds = [b"sol_escrow", produce.key().as_ref()], bump = sol_escrow.bump)]
+    pub sol_escrow: Account<'info, SolEscrow>,
+}
+
+/// Records a quality inspection and recomputes the batch's effective quality
+/// as the median of the inspection scores.
+pub fn verify_quality(
+    ctx: Context<VerifyQuality>,
+    verified_quality: u8,
+    grades: QualityGrades,
+    lab_report_hash: [u8; 32],
+) -> Result<()> {
+    require!(verified_quality <= MAX_QUALITY_SCORE, ErrorCode::InvalidQualityScore);
+    grades.validate()?;
+
+    let inspector = ctx.accounts.verifier.key();
+    let is_certified = ctx
+        .accounts
+        .certification
+        .as_ref()
+        .is_some_and(|certification| certification.active);
+    let is_inspector = match ctx.accounts.verifier_account.role {
+        ParticipantRole::Wholesaler | ParticipantRole::Retailer => false,
+        ParticipantRole::Inspector if is_certified => true,
+        _ if is_certified => false,
+        _ => return Err(ErrorCode::Unauthorized.into()),
+    };
+
+    let produce = &mut ctx.accounts.produce;
+    require!(
+        matches!(
+            produce.status,
+            ProduceStatus::PickedUp | ProduceStatus::InTransit | ProduceStatus::Delivered
+        ) && (produce.escrowed_amount > 0 || produce.buyer == Pubkey::default()),
+        ErrorCode::InvalidStatus
+    );
+    require!(
+        inspector != produce.farmer && inspector != produce.transporter,
+        ErrorCode::ConflictOfInterest
+    );
+    let certified = is_certified && !produce.is_party(&inspector);
+    let uncertified_inspections = produce.inspection_scores.len() - produce.certified_inspections as usize;
+    require!(
+        produce.inspection_scores.len() < MAX_INSPECTIONS
+            && (certified || uncertified_inspections < MAX_INSPECTIONS - 1),
+        ErrorCode::TooManyInspections
+    );
+    let now = Clock::get()?.unix_timestamp;
+    let quality_config = &ctx.accounts.quality_config;
+
+    // Independent inspectors are paid per inspection out of the batch escrow,
+    // at the fee in force when the batch was funded.
+    let fee = if !is_inspector {
+        0
+    } else if produce.settlement_mint == Pubkey::default() {
+        quality_config.inspection_fee
+    } else {
+        produce.inspection_fee
+    };
+    if fee > 0 {
+        produce.escrowed_amount = produce
+            .escrowed_amount
+            .checked_sub(fee)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+        produce.inspection_fees = produce.inspection_fees.checked_add(fee).ok_or(ErrorCode::Overflow)?;
+        if let Some(certification) = ctx.accounts.certification.as_mut() {
+            certification.fees_earned = certification.fees_earned.checked_add(fee).ok_or(ErrorCode::Overflow)?;
+            certification.fees_unclaimed = certification.fees_unclaimed.checked_add(fee).ok_or(ErrorCode::Overflow)?;
+        }
+    }
+
+    let inspection = &mut ctx.accounts.inspection;
+    inspection.produce = produce.key();
+    inspection.inspector = inspector;
+    inspection.score = verified_quality;
+    inspection.grades = grades;
+    inspection.lab_report_hash = lab_report_hash;
+    inspection.certified = certified;
+    inspection.inspected_at = now;
+    inspection.bump = ctx.bumps.inspection;
+    inspection.independent = is_inspector;
+    inspection.fee = fee;
+    inspection.fee_claimed = false;
+
+    let first_inspection = produce.inspection_scores.is_empty();
+    let previous_quality = produce.verified_quality;
+    produce.inspection_scores.push(verified_quality);
+    if certified {
+        produce.certified_inspections = produce.certified_inspections.saturating_add(1);
+    }
+    if is_inspector {
+        produce.inspector_scores.push(verified_quality);
+    }
+    produce.verified_quality = if produce.inspector_scores.is_empty() {
+        median_score(&produce.inspection_scores)
+    } else {
+        median_score(&produce.inspector_scores)
+    };
+    // The farmer's reputation counts each batch once, at its effective quality.
+    let farmer_reputation = &mut ctx.accounts.farmer_reputation;
+    if first_inspection {
+        farmer_reputation.record_quality(produce.quality, produce.verified_quality, now);
+    } else {
+        farmer_reputation.revise_quality(produce.quality, previous_quality, produce.verified_quality, now);
+    }
+    produce.quality_verifier = inspector;
+    produce.last_updated = now;
+
+    quality_config.grade(produce);
+
+    emit!(QualityInspected {
+        produce: produce.key(),
+        inspector,
+        score: verified_quality,
+        certified,
+        effective_quality: produce.verified_quality,
+        fee,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VerifyQuality<'info> {
+    #[account(
+        mut,
+        seeds = [&b"produce"[..], &produce.produce_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub produce: Account<'info, Produce>,
+    #[account(
+        init,
+        payer = verifier,
+        space = 8 + QualityInspection::LEN,
+        seeds = [b"inspection", produce.key().as_ref(), verifier.key.as_ref()],
+        bump
+    )]
+    pub inspection: Account<'info, QualityInspection>,
+    #[account(
+        mut,
+        seeds = [&b"participant"[..], verifier.key.as_ref()],
+        bump,
+        constraint = verifier_account.owner == verifier.key()
+    )]
+    pub verifier_account: Account<'info, Participant>,
+    #[account(mut, seeds = [b"inspector_cert", verifier.key.as_ref()], bump = certification.bump)]
+    pub certification: Option<Account<'info, InspectorCertification>>,
+    #[account(seeds = [b"quality_config"], bump = quality_config.bump)]
+    pub quality_config: Account<'info, QualityConfig>,
+    #[account(mut, seeds = [b"reputation", produce.farmer.as_ref()], bump = farmer_reputation.bump)]
+    pub farmer_reputation: Account<'info, Reputation>,
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn median_of_no_scores_is_zero() {
+        assert_eq!(median_score(&[]), 0);
+    }
+
+    #[test]
+    fn median_of_odd_count_is_middle_score() {
+        assert_eq!(median_score(&[70]), 70);
+        assert_eq!(median_score(&[90, 10, 55]), 55);
+        assert_eq!(median_score(&[80, 80, 20, 95, 60]), 80);
+    }
+
+    #[test]
+    fn median_of_even_count_is_mean_of_middle_scores() {
+        assert_eq!(median_score(&[40, 60]), 50);
+        assert_eq!(median_score(&[100, 0, 71, 30]), 50);
+        // Rounds down.
+        assert_eq!(median_score(&[61, 60]), 60);
+    }
+
+    #[test]
+    fn median_does_not_overflow() {
+        assert_eq!(median_score(&[250, 200]), 225);
+        assert_eq!(median_score(&[u8::MAX, u8::MAX]), u8::MAX);
+    }
+
+    #[test]
+    fn certified_requirement_follows_batch_value_not_escrow_left() {
+        let config = QualityConfig { certified_threshold: 1_000, min_quality: 50, bump: 0, inspection_fee: 0 };
+        let mut produce = Produce::deserialize(&mut &[0u8; 1024][..]).unwrap();
+        produce.farmer_price = 900;
+        produce.transporter_fee = 100;
+        // Milestone releases and inspection fees have drawn the escrow down.
+        produce.escrowed_amount = 200;
+        produce.delivery_confirmed = true;
+        produce.inspection_scores = vec![20];
+        produce.verified_quality = 20;
+        config.grade(&mut produce);
+        assert!(!produce.dispute_raised);
+        assert!(produce.status == ProduceStatus::Harvested);
+
+        produce.certified_inspections = 1;
+        config.grade(&mut produce);
+        assert!(produce.dispute_raised);
+        assert!(produce.status == ProduceStatus::Disputed);
+    }
+}
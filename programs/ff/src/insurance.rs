@@ -0,0 +1,647 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::dispute::{Dispute, DisputeReason};
+use crate::error::ErrorCode;
+use crate::governance::GovernanceConfig;
+use crate::payment::{gross_up, transfer_fee, StakeAccount};
+use crate::produce::{Produce, ProduceStatus};
+use crate::reputation::{COLD_CHAIN_MAX_TEMP, COLD_CHAIN_MIN_TEMP};
+
+pub const CLAIM_REASON_COUNT: usize = 2;
+/// A policy can be claimed for this many seconds after it is written.
+pub const CLAIM_WINDOW: i64 = 90 * 24 * 60 * 60;
+/// Seconds between requesting and completing an underwriting withdrawal;
+/// outlasts `CLAIM_WINDOW`.
+pub const UNDERWRITING_WITHDRAWAL_DELAY: i64 = CLAIM_WINDOW + 24 * 60 * 60;
+
+/// Losses the insurance pool covers.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ClaimReason {
+    Spoilage,  // a spoilage dispute on the batch stood
+    ColdChain, // the pickup reading broke the cold chain
+}
+
+impl ClaimReason {
+    pub fn index(&self) -> usize {
+        match self {
+            ClaimReason::Spoilage => 0,
+            ClaimReason::ColdChain => 1,
+        }
+    }
+}
+
+/// Optional crop insurance, underwritten by stakers in proportion to their
+/// shares.
+#[account]
+pub struct InsurancePool {
+    pub mint: Pubkey,
+    pub premium_bps: u16,                            // of the insured value
+    pub coverage_bps: [u16; CLAIM_REASON_COUNT],     // payout per claim reason, of the insured value
+    pub total_shares: u64,
+    pub premiums_collected: u64,
+    pub claims_paid: u64,
+    pub bump: u8,
+    pub claims_outstanding: u64, // accepted claims the pool could not yet pay
+    pub balance: u64,            // tokens the pool has received and not paid out; excludes direct transfers to the vault
+}
+
+impl InsurancePool {
+    // Size: mint (32) + premium_bps (2) + coverage_bps (2 * CLAIM_REASON_COUNT) + total_shares (8)
+    // + premiums_collected (8) + claims_paid (8) + bump (1) + claims_outstanding (8) + balance (8) = 79.
+    pub const LEN: usize = 32 + 2 + 2 * CLAIM_REASON_COUNT + 8 + 8 + 8 + 1 + 8 + 8;
+
+    /// What the underwriters' shares are worth: the pool's balance less the
+    /// claims it still owes.
+    pub fn value(&self) -> u64 {
+        self.balance.saturating_sub(self.claims_outstanding)
+    }
+
+    /// Shares minted for `amount` deposited at the pool's current value.
+    pub fn shares_for(&self, amount: u64) -> Result<u64> {
+        if self.total_shares == 0 {
+            return Ok(amount);
+        }
+        let value = self.value();
+        require!(value > 0, ErrorCode::PoolDepleted);
+        let shares = (amount as u128 * self.total_shares as u128 / value as u128) as u64;
+        require!(shares > 0, ErrorCode::InsufficientFunds);
+        Ok(shares)
+    }
+
+    /// Tokens `shares` redeem for at the pool's current value.
+    pub fn redemption_value(&self, shares: u64) -> u64 {
+        (shares as u128 * self.value() as u128 / self.total_shares.max(1) as u128) as u64
+    }
+}
+
+/// Cover bought for one batch.
+#[account]
+pub struct InsurancePolicy {
+    pub produce: Pubkey,
+    pub insured: Pubkey, // the farmer or buyer who paid the premium; claims are paid to them
+    pub insured_value: u64,
+    pub premium: u64,
+    pub claimed: bool, // the claim has been paid in full
+    pub created_at: i64,
+    pub bump: u8,
+    pub claim_amount: u64, // coverage owed, fixed when the claim is accepted
+    pub claim_paid: u64,
+}
+
+impl InsurancePolicy {
+    // Size: produce (32) + insured (32) + insured_value (8) + premium (8) + claimed (1)
+    // + created_at (8) + bump (1) + claim_amount (8) + claim_paid (8) = 106.
+    pub const LEN: usize = 106;
+}
+
+/// A staker's share of the insurance pool.
+#[account]
+pub struct UnderwriterPosition {
+    pub owner: Pubkey,
+    pub shares: u64,
+    pub bump: u8,
+    pub pending_shares: u64,           // shares requested for withdrawal
+    pub withdrawal_requested_at: i64,
+}
+
+impl UnderwriterPosition {
+    // Size: owner (32) + shares (8) + bump (1) + pending_shares (8) + withdrawal_requested_at (8) = 57.
+    pub const LEN: usize = 57;
+}
+
+#[event]
+pub struct BatchInsured {
+    pub produce: Pubkey,
+    pub insured: Pubkey,
+    pub insured_value: u64,
+    pub premium: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InsuranceClaimPaid {
+    pub produce: Pubkey,
+    pub insured: Pubkey,
+    pub reason: ClaimReason,
+    pub amount: u64,
+    pub outstanding: u64, // still owed to the insured
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnderwritingWithdrawalRequested {
+    pub owner: Pubkey,
+    pub shares: u64,
+    pub available_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnderwritingChanged {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    pub deposited: bool,
+    pub timestamp: i64,
+}
+
+fn validate_terms(premium_bps: u16, coverage_bps: &[u16; CLAIM_REASON_COUNT]) -> Result<()> {
+    require!(
+        premium_bps <= 10_000 && coverage_bps.iter().all(|bps| *bps <= 10_000),
+        ErrorCode::InvalidConfig
+    );
+    Ok(())
+}
+
+/// Creates the insurance pool and its token account. Governance admin only.
+pub fn initialize_insurance_pool(
+    ctx: Context<InitializeInsurancePool>,
+    premium_bps: u16,
+    coverage_bps: [u16; CLAIM_REASON_COUNT],
+) -> Result<()> {
+    validate_terms(premium_bps, &coverage_bps)?;
+    let pool = &mut ctx.accounts.insurance_pool;
+    pool.mint = ctx.accounts.mint.key();
+    pool.premium_bps = premium_bps;
+    pool.coverage_bps = coverage_bps;
+    pool.total_shares = 0;
+    pool.premiums_collected = 0;
+    pool.claims_paid = 0;
+    pool.claims_outstanding = 0;
+    pool.balance = 0;
+    pool.bump = ctx.bumps.insurance_pool;
+    Ok(())
+}
+
+/// Updates the premium rate and policy table. Governance admin only; cover
+/// already bought keeps the premium paid but claims use the current table.
+pub fn update_insurance_pool(
+    ctx: Context<UpdateInsurancePool>,
+    premium_bps: u16,
+    coverage_bps: [u16; CLAIM_REASON_COUNT],
+) -> Result<()> {
+    validate_terms(premium_bps, &coverage_bps)?;
+    let pool = &mut ctx.accounts.insurance_pool;
+    pool.premium_bps = premium_bps;
+    pool.coverage_bps = coverage_bps;
+    Ok(())
+}
+
+/// Insures a freshly logged batch for its farmer price. The farmer or the
+/// buyer pays the premium and receives any claim.
+pub fn insure_batch(ctx: Context<InsureBatch>) -> Result<()> {
+    let produce = &ctx.accounts.produce;
+    require!(produce.status == ProduceStatus::Harvested, ErrorCode::InvalidStatus);
+    let insured = ctx.accounts.insured.key();
+    require!(insured == produce.farmer || insured == produce.buyer, ErrorCode::Unauthorized);
+
+    let pool = &mut ctx.accounts.insurance_pool;
+    let insured_value = produce.farmer_price;
+    let premium = (insured_value as u128 * pool.premium_bps as u128 / 10_000) as u64;
+    pool.premiums_collected = pool.premiums_collected.saturating_add(premium);
+    pool.balance = pool.balance.checked_add(premium).ok_or(ErrorCode::Overflow)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let policy = &mut ctx.accounts.policy;
+    policy.produce = produce.key();
+    policy.insured = insured;
+    policy.insured_value = insured_value;
+    policy.premium = premium;
+    policy.claimed = false;
+    policy.created_at = now;
+    policy.bump = ctx.bumps.policy;
+    policy.claim_amount = 0;
+    policy.claim_paid = 0;
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.insured_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.insurance_vault.to_account_info(),
+        authority: ctx.accounts.insured.to_account_info(),
+    };
+    // The insured covers any transfer fee so the pool receives the full premium.
+    token_interface::transfer_checked(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+        gross_up(&ctx.accounts.mint, premium)?,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(BatchInsured {
+        produce: policy.produce,
+        insured,
+        insured_value,
+        premium,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Pays out a policy per the pool's table, within `CLAIM_WINDOW` of buying
+/// cover; whatever the pool cannot pay stays owed.
+pub fn claim_insurance(ctx: Context<ClaimInsurance>, reason: ClaimReason) -> Result<()> {
+    let produce = &ctx.accounts.produce;
+    let eligible = match reason {
+        ClaimReason::Spoilage => ctx.accounts.dispute.as_ref().is_some_and(|dispute| {
+            dispute.produce == produce.key()
+                && dispute.reason == DisputeReason::Spoilage
+                && dispute.settled
+                && !dispute.resolution
+                && dispute.adjudicated()
+        }),
+        ClaimReason::ColdChain => {
+            produce.delivery_confirmed
+                && produce.transport_temp != -999
+                && !(COLD_CHAIN_MIN_TEMP..=COLD_CHAIN_MAX_TEMP).contains(&produce.transport_temp)
+        }
+    };
+    require!(eligible, ErrorCode::NotEligibleForClaim);
+
+    let now = Clock::get()?.unix_timestamp;
+    let policy = &mut ctx.accounts.policy;
+    require!(!policy.claimed, ErrorCode::AlreadyClaimed);
+    let pool = &mut ctx.accounts.insurance_pool;
+    if policy.claim_amount == 0 {
+        require!(now <= policy.created_at + CLAIM_WINDOW, ErrorCode::ClaimWindowClosed);
+        let coverage = (policy.insured_value as u128 * pool.coverage_bps[reason.index()] as u128 / 10_000) as u64;
+        require!(coverage > 0, ErrorCode::NotEligibleForClaim);
+        policy.claim_amount = coverage;
+        pool.claims_outstanding = pool.claims_outstanding.checked_add(coverage).ok_or(ErrorCode::Overflow)?;
+    }
+
+    let amount = (policy.claim_amount - policy.claim_paid).min(pool.balance);
+    policy.claim_paid += amount;
+    policy.claimed = policy.claim_paid == policy.claim_amount;
+    pool.claims_outstanding = pool.claims_outstanding.saturating_sub(amount);
+    pool.claims_paid = pool.claims_paid.saturating_add(amount);
+    pool.balance -= amount;
+
+    transfer_from_pool(
+        &ctx.accounts.token_program,
+        &ctx.accounts.insurance_vault,
+        &ctx.accounts.insured_token_account.to_account_info(),
+        &ctx.accounts.mint,
+        pool,
+        amount,
+    )?;
+
+    emit!(InsuranceClaimPaid {
+        produce: produce.key(),
+        insured: policy.insured,
+        reason,
+        amount,
+        outstanding: policy.claim_amount - policy.claim_paid,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Moves part of a stake from the stake vault into the insurance pool in
+/// exchange for pool shares.
+pub fn underwrite_insurance(ctx: Context<Underwrite>, amount: u64) -> Result<()> {
+    ctx.accounts
+        .stake_account
+        .withdraw(amount, Clock::get()?.unix_timestamp)?;
+
+    // Shares are priced at the pool's value before the deposit; only what
+    // reaches the pool after any transfer fee buys shares.
+    let received = amount
+        .checked_sub(transfer_fee(&ctx.accounts.mint, amount)?)
+        .ok_or(ErrorCode::Overflow)?;
+    let pool = &mut ctx.accounts.insurance_pool;
+    let shares = pool.shares_for(received)?;
+    pool.balance = pool.balance.checked_add(received).ok_or(ErrorCode::Overflow)?;
+    pool.total_shares = pool.total_shares.checked_add(shares).ok_or(ErrorCode::Overflow)?;
+    let position = &mut ctx.accounts.position;
+    position.owner = ctx.accounts.staker.key();
+    position.shares = position.shares.checked_add(shares).ok_or(ErrorCode::Overflow)?;
+    position.bump = ctx.bumps.position;
+
+    let bump_array = [ctx.bumps.stake_vault];
+    let signer: &[&[&[u8]]] = &[&[b"stake_vault".as_ref(), &bump_array]];
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.stake_vault.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.insurance_vault.to_account_info(),
+        authority: ctx.accounts.stake_vault.to_account_info(),
+    };
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(UnderwritingChanged {
+        owner: position.owner,
+        amount,
+        shares,
+        deposited: true,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Starts the lockup on withdrawing `shares` from the pool, replacing any
+/// earlier request.
+pub fn request_underwriting_withdrawal(ctx: Context<RequestUnderwritingWithdrawal>, shares: u64) -> Result<()> {
+    let position = &mut ctx.accounts.position;
+    require!(shares > 0 && position.shares >= shares, ErrorCode::InsufficientFunds);
+    let now = Clock::get()?.unix_timestamp;
+    position.pending_shares = shares;
+    position.withdrawal_requested_at = now;
+
+    emit!(UnderwritingWithdrawalRequested {
+        owner: position.owner,
+        shares,
+        available_at: now + UNDERWRITING_WITHDRAWAL_DELAY,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Redeems pool shares requested for withdrawal once their lockup has passed.
+pub fn withdraw_underwriting(ctx: Context<WithdrawUnderwriting>, shares: u64) -> Result<()> {
+    let position = &mut ctx.accounts.position;
+    require!(
+        shares <= position.pending_shares
+            && Clock::get()?.unix_timestamp >= position.withdrawal_requested_at + UNDERWRITING_WITHDRAWAL_DELAY,
+        ErrorCode::WithdrawalLocked
+    );
+    require!(position.shares >= shares, ErrorCode::InsufficientFunds);
+    position.shares -= shares;
+    position.pending_shares -= shares;
+
+    let pool = &mut ctx.accounts.insurance_pool;
+    let amount = pool.redemption_value(shares);
+    pool.total_shares -= shares;
+    pool.balance -= amount;
+    let received = amount
+        .checked_sub(transfer_fee(&ctx.accounts.mint, amount)?)
+        .ok_or(ErrorCode::Overflow)?;
+    let stake_account = &mut ctx.accounts.stake_account;
+    stake_account.amount = stake_account.amount.checked_add(received).ok_or(ErrorCode::Overflow)?;
+
+    transfer_from_pool(
+        &ctx.accounts.token_program,
+        &ctx.accounts.insurance_vault,
+        &ctx.accounts.stake_vault.to_account_info(),
+        &ctx.accounts.mint,
+        pool,
+        amount,
+    )?;
+
+    emit!(UnderwritingChanged {
+        owner: position.owner,
+        amount,
+        shares,
+        deposited: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Transfers `amount` out of the insurance pool's token account, signed by
+/// the pool PDA.
+fn transfer_from_pool<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    insurance_vault: &InterfaceAccount<'info, TokenAccount>,
+    to: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    pool: &Account<'info, InsurancePool>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let bump_array = [pool.bump];
+    let signer: &[&[&[u8]]] = &[&[b"insurance_pool".as_ref(), &bump_array]];
+    let cpi_accounts = TransferChecked {
+        from: insurance_vault.to_account_info(),
+        mint: mint.to_account_info(),
+        to: to.clone(),
+        authority: pool.to_account_info(),
+    };
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer),
+        amount,
+        mint.decimals,
+    )
+}
+
+#[derive(Accounts)]
+pub struct InitializeInsurancePool<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + InsurancePool::LEN,
+        seeds = [b"insurance_pool"],
+        bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    /// Holds premiums and underwriting capital.
+    #[account(
+        init,
+        payer = admin,
+        token::mint = mint,
+        token::authority = insurance_pool,
+        token::token_program = token_program,
+        seeds = [b"insurance_vault"],
+        bump
+    )]
+    pub insurance_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"stake_vault"], bump)]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = stake_vault.mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateInsurancePool<'info> {
+    #[account(mut, seeds = [b"insurance_pool"], bump = insurance_pool.bump)]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InsureBatch<'info> {
+    #[account(seeds = [b"produce", &produce.produce_id.to_le_bytes()[..]], bump)]
+    pub produce: Account<'info, Produce>,
+    #[account(
+        init,
+        payer = insured,
+        space = 8 + InsurancePolicy::LEN,
+        seeds = [b"insurance_policy", produce.key().as_ref()],
+        bump
+    )]
+    pub policy: Account<'info, InsurancePolicy>,
+    #[account(mut, seeds = [b"insurance_pool"], bump = insurance_pool.bump)]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    #[account(mut, seeds = [b"insurance_vault"], bump)]
+    pub insurance_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub insured: Signer<'info>,
+    #[account(mut)]
+    pub insured_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = insurance_pool.mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimInsurance<'info> {
+    pub produce: Account<'info, Produce>,
+    #[account(
+        mut,
+        seeds = [b"insurance_policy", produce.key().as_ref()],
+        bump = policy.bump,
+        has_one = insured @ ErrorCode::Unauthorized
+    )]
+    pub policy: Account<'info, InsurancePolicy>,
+    /// Required for spoilage claims.
+    pub dispute: Option<Account<'info, Dispute>>,
+    #[account(mut, seeds = [b"insurance_pool"], bump = insurance_pool.bump)]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    #[account(mut, seeds = [b"insurance_vault"], bump)]
+    pub insurance_vault: InterfaceAccount<'info, TokenAccount>,
+    pub insured: Signer<'info>,
+    #[account(
+        mut,
+        constraint = insured_token_account.owner == insured.key() @ ErrorCode::InvalidPayoutAccount
+    )]
+    pub insured_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = insurance_pool.mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct Underwrite<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump = stake_account.bump,
+        constraint = stake_account.owner == staker.key()
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + UnderwriterPosition::LEN,
+        seeds = [b"underwriter", staker.key().as_ref()],
+        bump
+    )]
+    pub position: Account<'info, UnderwriterPosition>,
+    #[account(mut, seeds = [b"insurance_pool"], bump = insurance_pool.bump)]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    #[account(mut, seeds = [b"insurance_vault"], bump)]
+    pub insurance_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, seeds = [b"stake_vault"], bump)]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = insurance_pool.mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RequestUnderwritingWithdrawal<'info> {
+    pub staker: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"underwriter", staker.key().as_ref()],
+        bump = position.bump,
+        constraint = position.owner == staker.key()
+    )]
+    pub position: Account<'info, UnderwriterPosition>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawUnderwriting<'info> {
+    pub staker: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump = stake_account.bump,
+        constraint = stake_account.owner == staker.key()
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    #[account(
+        mut,
+        seeds = [b"underwriter", staker.key().as_ref()],
+        bump = position.bump,
+        constraint = position.owner == staker.key()
+    )]
+    pub position: Account<'info, UnderwriterPosition>,
+    #[account(mut, seeds = [b"insurance_pool"], bump = insurance_pool.bump)]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    #[account(mut, seeds = [b"insurance_vault"], bump)]
+    pub insurance_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, seeds = [b"stake_vault"], bump)]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = insurance_pool.mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(total_shares: u64, balance: u64, claims_outstanding: u64) -> InsurancePool {
+        InsurancePool {
+            mint: Pubkey::default(),
+            premium_bps: 0,
+            coverage_bps: [0; CLAIM_REASON_COUNT],
+            total_shares,
+            premiums_collected: 0,
+            claims_paid: 0,
+            bump: 0,
+            claims_outstanding,
+            balance,
+        }
+    }
+
+    #[test]
+    fn first_deposit_is_priced_one_to_one() {
+        assert_eq!(pool(0, 0, 0).shares_for(500).unwrap(), 500);
+    }
+
+    #[test]
+    fn shares_are_priced_at_value_net_of_claims_owed() {
+        // 1,000 shares backed by 2,000 tokens, 500 of them owed to claims.
+        let pool = pool(1_000, 2_000, 500);
+        assert_eq!(pool.shares_for(300).unwrap(), 200);
+        assert_eq!(pool.redemption_value(200), 300);
+    }
+
+    #[test]
+    fn deposits_are_refused_while_shares_are_worthless() {
+        assert!(pool(1_000, 0, 0).shares_for(500).is_err());
+        assert!(pool(1_000, 400, 400).shares_for(500).is_err());
+    }
+
+    #[test]
+    fn deposits_that_would_mint_no_shares_are_refused() {
+        // One share worth 1,000 tokens: a deposit of 999 would round to nothing.
+        assert!(pool(1, 1_000, 0).shares_for(999).is_err());
+        assert_eq!(pool(1, 1_000, 0).shares_for(1_000).unwrap(), 1);
+    }
+}
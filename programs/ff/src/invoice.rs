@@ -0,0 +1,429 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+use crate::payment::SettlementCompleted;
+use crate::produce::Produce;
+
+/// Maximum number of line items on an invoice.
+pub const MAX_LINE_ITEMS: usize = 8;
+/// Column header of `Invoice::to_csv`.
+pub const CSV_HEADER: &str =
+    "sequence,produce_id,mint,issued_at,slot,kind,party,amount";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LineItemKind {
+    Goods,          // farmer price of the quantity received
+    Freight,        // transporter fee
+    TransitLoss,    // losses beyond tolerance: credited to the farmer, debited to the transporter
+    QualityBonus,
+    QualityPenalty, // includes cold-chain penalties on the transporter
+    InspectionFees,
+    ProtocolFee,
+}
+
+impl LineItemKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LineItemKind::Goods => "goods",
+            LineItemKind::Freight => "freight",
+            LineItemKind::TransitLoss => "transit_loss",
+            LineItemKind::QualityBonus => "quality_bonus",
+            LineItemKind::QualityPenalty => "quality_penalty",
+            LineItemKind::InspectionFees => "inspection_fees",
+            LineItemKind::ProtocolFee => "protocol_fee",
+        }
+    }
+}
+
+/// One charge on an invoice. `amount` is negative for deductions.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LineItem {
+    pub kind: LineItemKind,
+    pub party: Pubkey, // who the amount is paid to; default for fees spread over several inspectors, the treasury account for protocol fees
+    pub amount: i64,
+}
+
+impl LineItem {
+    // Size: kind (1) + party (32) + amount (8) = 41.
+    pub const LEN: usize = 41;
+}
+
+/// Numbers invoices in the order batches settle.
+#[account]
+pub struct InvoiceCounter {
+    pub next_sequence: u64,
+    pub bump: u8,
+}
+
+impl InvoiceCounter {
+    // Size: next_sequence (8) + bump (1) = 9.
+    pub const LEN: usize = 9;
+}
+
+/// Accounting record of a settled batch; line items add up to `total`.
+#[account]
+pub struct Invoice {
+    pub produce: Pubkey,
+    pub produce_id: u64,
+    pub sequence: u64,
+    pub slot: u64, // slot of the settling transaction
+    pub farmer: Pubkey,
+    pub transporter: Pubkey,
+    pub buyer: Pubkey,
+    pub mint: Pubkey,
+    pub quantity: u64,
+    pub received_quantity: u64,
+    pub price_per_unit: u64,
+    pub line_items: Vec<LineItem>,
+    pub total: u64,
+    pub buyer_refund: u64,
+    pub harvest_date: i64,
+    pub picked_up_at: i64,
+    pub issued_at: i64,
+    pub bump: u8,
+}
+
+impl Invoice {
+    // Size: produce (32) + produce_id (8) + sequence (8) + slot (8) + farmer (32) + transporter (32)
+    // + buyer (32) + mint (32) + quantity (8) + received_quantity (8) + price_per_unit (8)
+    // + line_items (4 + LineItem::LEN * MAX_LINE_ITEMS) + total (8) + buyer_refund (8)
+    // + harvest_date (8) + picked_up_at (8) + issued_at (8) + bump (1) = 581.
+    pub const LEN: usize = 32 + 8 + 8 + 8 + 32 + 32 + 32 + 32 + 8 + 8 + 8
+        + (4 + LineItem::LEN * MAX_LINE_ITEMS) + 8 + 8 + 8 + 8 + 8 + 1;
+
+    fn push_item(&mut self, kind: LineItemKind, party: Pubkey, amount: i64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        require!(self.line_items.len() < MAX_LINE_ITEMS, ErrorCode::Overflow);
+        self.line_items.push(LineItem { kind, party, amount });
+        Ok(())
+    }
+
+    fn push_adjustment(&mut self, party: Pubkey, amount: i64) -> Result<()> {
+        let kind = if amount > 0 {
+            LineItemKind::QualityBonus
+        } else {
+            LineItemKind::QualityPenalty
+        };
+        self.push_item(kind, party, amount)
+    }
+
+    /// Itemizes what the buyer was billed for a settled batch and sets the
+    /// total and refund.
+    fn bill(&mut self, produce: &Produce, settlement: &SettlementCompleted, treasury: Pubkey) -> Result<()> {
+        let loss_charge = settlement.transit_loss_charge as i64;
+        let farmer_payee = produce.farmer_payee();
+        self.line_items = Vec::new();
+        self.push_item(LineItemKind::Goods, farmer_payee, settlement.farmer_base as i64 - loss_charge)?;
+        self.push_item(LineItemKind::Freight, produce.transporter, produce.transporter_fee as i64)?;
+        self.push_item(LineItemKind::TransitLoss, farmer_payee, loss_charge)?;
+        self.push_item(LineItemKind::TransitLoss, produce.transporter, -loss_charge)?;
+        self.push_adjustment(farmer_payee, settlement.farmer_adjustment)?;
+        self.push_adjustment(produce.transporter, settlement.transporter_adjustment)?;
+        self.push_item(LineItemKind::InspectionFees, Pubkey::default(), produce.inspection_fees as i64)?;
+        self.push_item(LineItemKind::ProtocolFee, treasury, settlement.protocol_fees as i64)?;
+
+        self.total = settlement
+            .farmer_paid
+            .checked_add(settlement.transporter_paid)
+            .and_then(|paid| paid.checked_add(produce.inspection_fees))
+            .and_then(|paid| paid.checked_add(settlement.protocol_fees))
+            .ok_or(ErrorCode::Overflow)?;
+        self.buyer_refund = settlement.buyer_refund;
+        Ok(())
+    }
+
+    /// Renders the invoice as a JSON object. Amounts are in the mint's base
+    /// units and timestamps in Unix seconds.
+    pub fn to_json(&self) -> String {
+        let line_items: Vec<String> = self
+            .line_items
+            .iter()
+            .map(|item| {
+                format!(
+                    "{{\"kind\":{},\"party\":{},\"amount\":{}}}",
+                    json_string(item.kind.label()),
+                    json_string(&item.party.to_string()),
+                    item.amount
+                )
+            })
+            .collect();
+        format!(
+            concat!(
+                "{{\"sequence\":{},\"produce\":{},\"produce_id\":{},\"slot\":{},",
+                "\"farmer\":{},\"transporter\":{},\"buyer\":{},\"mint\":{},",
+                "\"quantity\":{},\"received_quantity\":{},\"price_per_unit\":{},",
+                "\"line_items\":[{}],\"total\":{},\"buyer_refund\":{},",
+                "\"harvest_date\":{},\"picked_up_at\":{},\"issued_at\":{}}}"
+            ),
+            self.sequence,
+            json_string(&self.produce.to_string()),
+            self.produce_id,
+            self.slot,
+            json_string(&self.farmer.to_string()),
+            json_string(&self.transporter.to_string()),
+            json_string(&self.buyer.to_string()),
+            json_string(&self.mint.to_string()),
+            self.quantity,
+            self.received_quantity,
+            self.price_per_unit,
+            line_items.join(","),
+            self.total,
+            self.buyer_refund,
+            self.harvest_date,
+            self.picked_up_at,
+            self.issued_at
+        )
+    }
+
+    /// Renders the invoice as CSV, one row per line item under `CSV_HEADER`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(CSV_HEADER);
+        csv.push('\n');
+        for item in &self.line_items {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                self.sequence,
+                self.produce_id,
+                csv_field(&self.mint.to_string()),
+                self.issued_at,
+                self.slot,
+                csv_field(item.kind.label()),
+                csv_field(&item.party.to_string()),
+                item.amount
+            ));
+        }
+        csv
+    }
+}
+
+/// Quotes `value` as a JSON string, escaping quotes, backslashes and control
+/// characters.
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Quotes a CSV field (RFC 4180) if it contains a separator, quote or line
+/// break; embedded quotes are doubled.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Fills in the invoice of a batch that has just settled and takes the next
+/// sequence number.
+fn issue_invoice(
+    invoice: &mut Invoice,
+    counter: &mut InvoiceCounter,
+    produce: &Produce,
+    settlement: &SettlementCompleted,
+    treasury: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    invoice.produce = settlement.produce;
+    invoice.produce_id = produce.produce_id;
+    invoice.sequence = counter.next_sequence;
+    invoice.slot = clock.slot;
+    invoice.farmer = produce.farmer;
+    invoice.transporter = produce.transporter;
+    invoice.buyer = produce.buyer;
+    invoice.mint = produce.settlement_mint;
+    invoice.quantity = produce.quantity;
+    invoice.received_quantity = produce.received_quantity;
+    invoice.price_per_unit = produce.price_per_unit;
+    invoice.harvest_date = produce.harvest_date;
+    invoice.picked_up_at = produce.picked_up_at;
+    invoice.issued_at = clock.unix_timestamp;
+    counter.next_sequence = counter.next_sequence.checked_add(1).ok_or(ErrorCode::Overflow)?;
+
+    invoice.bill(produce, settlement, treasury)
+}
+
+/// Invoice and sequence counter of a batch being settled. The invoice is
+/// created on first use and filled in by the call that first pays out the
+/// batch escrow.
+#[derive(Accounts)]
+pub struct IssueInvoice<'info> {
+    pub produce: Account<'info, Produce>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Invoice::LEN,
+        seeds = [b"invoice", produce.key().as_ref()],
+        bump
+    )]
+    pub invoice: Account<'info, Invoice>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + InvoiceCounter::LEN,
+        seeds = [b"invoice_counter"],
+        bump
+    )]
+    pub invoice_counter: Account<'info, InvoiceCounter>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> IssueInvoice<'info> {
+    /// Issues the invoice of the batch `settlement` reports on.
+    pub fn issue(
+        &mut self,
+        bumps: &IssueInvoiceBumps,
+        produce: &Produce,
+        settlement: &SettlementCompleted,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        require!(self.produce.key() == settlement.produce, ErrorCode::InvoiceAccountMismatch);
+        self.invoice.bump = bumps.invoice;
+        self.invoice_counter.bump = bumps.invoice_counter;
+        issue_invoice(&mut self.invoice, &mut self.invoice_counter, produce, settlement, treasury)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payment::base_payouts;
+
+    /// Zero-initialized account state, as a freshly allocated account holds.
+    fn blank<T: AnchorDeserialize>() -> T {
+        T::deserialize(&mut &[0u8; 1024][..]).unwrap()
+    }
+
+    fn settled_batch() -> (Produce, SettlementCompleted) {
+        let mut produce: Produce = blank();
+        produce.farmer = Pubkey::new_unique();
+        produce.transporter = Pubkey::new_unique();
+        produce.quantity = 100;
+        produce.received_quantity = 90;
+        produce.farmer_price = 1_000;
+        produce.transporter_fee = 200;
+        produce.delivery_confirmed = true;
+        produce.transit_loss_charge = 50;
+        produce.inspection_fees = 30;
+        let (farmer_base, transporter_base) = base_payouts(&produce);
+        produce.farmer_released = farmer_base + 20;
+        produce.transporter_released = transporter_base - 40;
+        let settlement = SettlementCompleted {
+            produce: Pubkey::new_unique(),
+            buyer: Pubkey::new_unique(),
+            farmer_base,
+            transporter_base,
+            transit_loss_charge: 50,
+            farmer_adjustment: 20,
+            transporter_adjustment: -40,
+            farmer_paid: produce.farmer_released,
+            transporter_paid: produce.transporter_released,
+            protocol_fees: 12,
+            buyer_refund: 300,
+            timestamp: 0,
+        };
+        (produce, settlement)
+    }
+
+    fn billed_invoice() -> Invoice {
+        let (produce, settlement) = settled_batch();
+        let mut invoice: Invoice = blank();
+        invoice.bill(&produce, &settlement, Pubkey::new_unique()).unwrap();
+        invoice
+    }
+
+    #[test]
+    fn line_items_add_up_to_total() {
+        let invoice = billed_invoice();
+        let kinds: Vec<LineItemKind> = invoice.line_items.iter().map(|item| item.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                LineItemKind::Goods,
+                LineItemKind::Freight,
+                LineItemKind::TransitLoss,
+                LineItemKind::TransitLoss,
+                LineItemKind::QualityBonus,
+                LineItemKind::QualityPenalty,
+                LineItemKind::InspectionFees,
+                LineItemKind::ProtocolFee,
+            ]
+        );
+        let sum: i64 = invoice.line_items.iter().map(|item| item.amount).sum();
+        assert_eq!(invoice.total, 970 + 110 + 30 + 12);
+        assert_eq!(sum, invoice.total as i64);
+        assert_eq!(invoice.buyer_refund, 300);
+    }
+
+    #[test]
+    fn zero_amounts_are_not_itemized() {
+        let (mut produce, mut settlement) = settled_batch();
+        produce.inspection_fees = 0;
+        settlement.protocol_fees = 0;
+        settlement.farmer_adjustment = 0;
+        settlement.farmer_paid = settlement.farmer_base;
+        let mut invoice: Invoice = blank();
+        invoice.bill(&produce, &settlement, Pubkey::new_unique()).unwrap();
+        assert_eq!(invoice.line_items.len(), 5);
+        let sum: i64 = invoice.line_items.iter().map(|item| item.amount).sum();
+        assert_eq!(sum, invoice.total as i64);
+    }
+
+    #[test]
+    fn csv_has_header_and_one_row_per_line_item() {
+        let invoice = billed_invoice();
+        let csv = invoice.to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some(CSV_HEADER));
+        let rows: Vec<&str> = lines.collect();
+        assert_eq!(rows.len(), invoice.line_items.len());
+        let columns = CSV_HEADER.split(',').count();
+        for (row, item) in rows.iter().zip(&invoice.line_items) {
+            let fields: Vec<&str> = row.split(',').collect();
+            assert_eq!(fields.len(), columns);
+            assert_eq!(fields[5], item.kind.label());
+            assert_eq!(fields[7], item.amount.to_string());
+        }
+    }
+
+    #[test]
+    fn json_lists_line_items_and_total() {
+        let invoice = billed_invoice();
+        let json = invoice.to_json();
+        assert!(json.starts_with('{') && json.ends_with('}'));
+        assert!(json.contains(&format!("\"farmer\":\"{}\"", invoice.farmer)));
+        assert!(json.contains("{\"kind\":\"transit_loss\""));
+        assert!(json.contains("\"amount\":-40}"));
+        assert!(json.contains("\"total\":1122,\"buyer_refund\":300"));
+    }
+
+    #[test]
+    fn json_strings_are_escaped() {
+        assert_eq!(json_string("goods"), "\"goods\"");
+        assert_eq!(json_string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(json_string("line\nbreak\ttab\u{1}"), "\"line\\nbreak\\ttab\\u0001\"");
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_when_needed() {
+        assert_eq!(csv_field("quality_bonus"), "quality_bonus");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+}
@@ -7,12 +7,25 @@ pub mod produce;
 pub mod payment;
 pub mod dispute;
 pub mod governance;
+pub mod arbitrator;
+pub mod reputation;
+pub mod inspection;
+pub mod invoice;
+pub mod factoring;
+pub mod insurance;
+pub mod treasury;
 use error::*;
 use participant::*;
 use produce::*;
 use payment::*;
 use dispute::*;
 use governance::*;
+use arbitrator::*;
+use reputation::*;
+use inspection::*;
+use factoring::*;
+use insurance::*;
+use treasury::*;
 
 #[program]
 pub mod decentralized_ag_supply {
@@ -63,20 +76,24 @@ pub mod decentralized_ag_supply {
         produce::record_delivery(ctx)
     }
     
-    pub fn confirm_delivery(mut ctx: Context<ConfirmDelivery>) -> Result<()> {
+    pub fn confirm_delivery(mut ctx: Context<ConfirmDelivery>, received_quantity: u64) -> Result<()> {
         let mut bumps_map: std::collections::BTreeMap<String, u8> = std::collections::BTreeMap::new();
         bumps_map.insert("produce".to_string(), ctx.bumps.produce);
         bumps_map.insert("retailer_account".to_string(), ctx.bumps.retailer_account);
-        bumps_map.insert("payment_vault".to_string(), ctx.bumps.payment_vault);
         
         // Process status update and payments in one function
-        produce::confirm_delivery(&mut ctx.accounts, &bumps_map)?;
+        produce::confirm_delivery(&mut ctx.accounts, &bumps_map, received_quantity)?;
         
         Ok(())
     }
     
-    pub fn verify_quality(ctx: Context<VerifyQuality>, verified_quality: u8) -> Result<()> {
-        produce::verify_quality(ctx, verified_quality)
+    pub fn verify_quality(
+        ctx: Context<VerifyQuality>,
+        verified_quality: u8,
+        grades: QualityGrades,
+        lab_report_hash: [u8; 32],
+    ) -> Result<()> {
+        inspection::verify_quality(ctx, verified_quality, grades, lab_report_hash)
     }
     
     pub fn initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
@@ -87,16 +104,104 @@ pub mod decentralized_ag_supply {
         payment::fund_vault(ctx, amount)
     }
     
-    pub fn process_payment(ctx: Context<ProcessPayment>) -> Result<()> {
+    pub fn process_payment(ctx: Context<SettleBatch>) -> Result<()> {
         payment::process_payment(ctx)
     }
     
-    pub fn raise_dispute(ctx: Context<RaiseDispute>, description: String) -> Result<()> {
-        dispute::raise_dispute(ctx, description)
+    pub fn fund_vault_sol(ctx: Context<FundVaultSol>, amount: u64) -> Result<()> {
+        payment::fund_vault_sol(ctx, amount)
+    }
+    
+    pub fn process_payment_sol(ctx: Context<SettleBatchSol>) -> Result<()> {
+        payment::process_payment_sol(ctx)
+    }
+    
+    pub fn set_milestone_schedule(
+        ctx: Context<SetPaymentTerms>,
+        milestone_bps: [u16; MILESTONE_COUNT],
+    ) -> Result<()> {
+        payment::set_milestone_schedule(ctx, milestone_bps)
+    }
+    
+    pub fn set_unit_price(ctx: Context<SetPaymentTerms>, price_per_unit: u64) -> Result<()> {
+        payment::set_unit_price(ctx, price_per_unit)
+    }
+    
+    pub fn initialize_payment_policy(
+        ctx: Context<InitializePaymentPolicy>,
+        shrinkage_tolerance_bps: u16,
+    ) -> Result<()> {
+        payment::initialize_payment_policy(ctx, shrinkage_tolerance_bps)
+    }
+    
+    pub fn update_payment_policy(
+        ctx: Context<UpdatePaymentPolicy>,
+        shrinkage_tolerance_bps: u16,
+    ) -> Result<()> {
+        payment::update_payment_policy(ctx, shrinkage_tolerance_bps)
+    }
+    
+    pub fn release_milestone(ctx: Context<ProcessPayment>, milestone: Milestone) -> Result<()> {
+        payment::release_milestone(ctx, milestone)
+    }
+    
+    pub fn release_milestone_sol(ctx: Context<ProcessPaymentSol>, milestone: Milestone) -> Result<()> {
+        payment::release_milestone_sol(ctx, milestone)
+    }
+    
+    pub fn initialize_dispute_config(
+        ctx: Context<InitializeDisputeConfig>,
+        params: DisputeParams,
+    ) -> Result<()> {
+        dispute::initialize_dispute_config(ctx, params)
+    }
+    
+    pub fn update_dispute_config(ctx: Context<UpdateDisputeConfig>, params: DisputeParams) -> Result<()> {
+        dispute::update_dispute_config(ctx, params)
+    }
+    
+    pub fn raise_dispute(
+        ctx: Context<RaiseDispute>,
+        reason: DisputeReason,
+        description: String,
+    ) -> Result<()> {
+        dispute::raise_dispute(ctx, reason, description)
+    }
+    
+    pub fn submit_evidence(
+        ctx: Context<SubmitEvidence>,
+        content_hash: [u8; 32],
+        uri: String,
+    ) -> Result<()> {
+        dispute::submit_evidence(ctx, content_hash, uri)
+    }
+    
+    pub fn assign_panel(ctx: Context<AssignPanel>) -> Result<()> {
+        dispute::assign_panel(ctx)
+    }
+    
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        resolution: bool,
+        split: SettlementSplit,
+    ) -> Result<()> {
+        dispute::resolve_dispute(ctx, resolution, split)
+    }
+    
+    pub fn answer_dispute(ctx: Context<AnswerDispute>) -> Result<()> {
+        dispute::answer_dispute(ctx)
+    }
+    
+    pub fn appeal_dispute(ctx: Context<AppealDispute>) -> Result<()> {
+        dispute::appeal_dispute(ctx)
+    }
+    
+    pub fn timeout_dispute(ctx: Context<TimeoutDispute>) -> Result<()> {
+        dispute::timeout_dispute(ctx)
     }
     
-    pub fn resolve_dispute(ctx: Context<ResolveDispute>, resolution: bool) -> Result<()> {
-        dispute::resolve_dispute(ctx, resolution)
+    pub fn finalize_dispute(ctx: Context<FinalizeDispute>) -> Result<()> {
+        dispute::finalize_dispute(ctx)
     }
     
     pub fn stake_tokens(ctx: Context<StakeTokens>, amount: u64) -> Result<()> {
@@ -107,23 +212,250 @@ pub mod decentralized_ag_supply {
         payment::unstake_tokens(ctx, amount)
     }
     
+    pub fn initialize_governance(
+        ctx: Context<InitializeGovernance>,
+        voting_period: i64,
+        quorum: u64,
+        min_proposer_stake: u64,
+        timelock: i64,
+    ) -> Result<()> {
+        governance::initialize_governance(ctx, voting_period, quorum, min_proposer_stake, timelock)
+    }
+    
+    pub fn update_governance_config(
+        ctx: Context<UpdateGovernanceConfig>,
+        voting_period: i64,
+        quorum: u64,
+        min_proposer_stake: u64,
+        timelock: i64,
+    ) -> Result<()> {
+        governance::update_governance_config(ctx, voting_period, quorum, min_proposer_stake, timelock)
+    }
+    
+    pub fn set_governance_electorate(
+        ctx: Context<UpdateGovernanceConfig>,
+        role_weights: [u16; participant::ROLE_COUNT],
+        eligible_roles: [u8; governance::PROPOSAL_TYPE_COUNT],
+    ) -> Result<()> {
+        governance::set_governance_electorate(ctx, role_weights, eligible_roles)
+    }
+    
     pub fn create_proposal(
         ctx: Context<CreateProposal>,
-        proposal_id: u64,
+        proposal_type: ProposalType,
+        action: ProposalAction,
         description: String,
     ) -> Result<()> {
-        governance::create_proposal(ctx, proposal_id, description)
+        governance::create_proposal(ctx, proposal_type, action, description)
     }
     
     pub fn vote_proposal(
         ctx: Context<VoteProposal>,
         proposal_id: u64,
-        vote_for: bool,
+        choice: VoteChoice,
     ) -> Result<()> {
-        governance::vote_proposal(ctx, proposal_id, vote_for)
+        governance::vote_proposal(ctx, proposal_id, choice)
+    }
+    
+    pub fn change_vote(ctx: Context<ChangeVote>, choice: VoteChoice) -> Result<()> {
+        governance::change_vote(ctx, choice)
+    }
+    
+    pub fn withdraw_vote(ctx: Context<WithdrawVote>) -> Result<()> {
+        governance::withdraw_vote(ctx)
+    }
+    
+    pub fn delegate_votes(ctx: Context<DelegateVotes>) -> Result<()> {
+        governance::delegate_votes(ctx)
+    }
+    
+    pub fn undelegate_votes(ctx: Context<UndelegateVotes>) -> Result<()> {
+        governance::undelegate_votes(ctx)
+    }
+    
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        governance::cancel_proposal(ctx)
+    }
+    
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        governance::finalize_proposal(ctx)
+    }
+    
+    pub fn queue_proposal(ctx: Context<QueueProposal>) -> Result<()> {
+        governance::queue_proposal(ctx)
     }
     
     pub fn execute_proposal(ctx: Context<ExecuteProposal>, proposal_id: u64) -> Result<()> {
         governance::execute_proposal(ctx, proposal_id)
     }
+    
+    pub fn initialize_arbitrator_registry(ctx: Context<InitializeArbitratorRegistry>) -> Result<()> {
+        arbitrator::initialize_arbitrator_registry(ctx)
+    }
+    
+    pub fn appoint_arbitrator(ctx: Context<ManageArbitrator>, arbitrator: Pubkey) -> Result<()> {
+        arbitrator::appoint_arbitrator(ctx, arbitrator)
+    }
+    
+    pub fn suspend_arbitrator(ctx: Context<ManageArbitrator>, arbitrator: Pubkey) -> Result<()> {
+        arbitrator::suspend_arbitrator(ctx, arbitrator)
+    }
+    
+    pub fn execute_arbitrator_proposal(ctx: Context<ExecuteArbitratorProposal>) -> Result<()> {
+        arbitrator::execute_arbitrator_proposal(ctx)
+    }
+    
+    pub fn claim_arbitration_fees(ctx: Context<ClaimArbitrationFees>) -> Result<()> {
+        arbitrator::claim_arbitration_fees(ctx)
+    }
+    
+    pub fn initialize_reputation(ctx: Context<InitializeReputation>) -> Result<()> {
+        reputation::initialize_reputation(ctx)
+    }
+    
+    pub fn initialize_quality_config(
+        ctx: Context<InitializeQualityConfig>,
+        certified_threshold: u64,
+        min_quality: u8,
+        inspection_fee: u64,
+    ) -> Result<()> {
+        inspection::initialize_quality_config(ctx, certified_threshold, min_quality, inspection_fee)
+    }
+    
+    pub fn update_quality_config(
+        ctx: Context<UpdateQualityConfig>,
+        certified_threshold: u64,
+        min_quality: u8,
+        inspection_fee: u64,
+    ) -> Result<()> {
+        inspection::update_quality_config(ctx, certified_threshold, min_quality, inspection_fee)
+    }
+    
+    pub fn certify_inspector(ctx: Context<CertifyInspector>, inspector: Pubkey) -> Result<()> {
+        inspection::certify_inspector(ctx, inspector)
+    }
+    
+    pub fn revoke_inspector(ctx: Context<RevokeInspector>) -> Result<()> {
+        inspection::revoke_inspector(ctx)
+    }
+    
+    pub fn execute_inspector_proposal(ctx: Context<ExecuteInspectorProposal>, inspector: Pubkey) -> Result<()> {
+        inspection::execute_inspector_proposal(ctx, inspector)
+    }
+    
+    pub fn claim_inspection_fees(ctx: Context<ClaimInspectionFees>) -> Result<()> {
+        inspection::claim_inspection_fees(ctx)
+    }
+    
+    pub fn initialize_mint_allowlist(ctx: Context<InitializeMintAllowlist>) -> Result<()> {
+        payment::initialize_mint_allowlist(ctx)
+    }
+    
+    pub fn allow_mint(ctx: Context<ManageMintAllowlist>, mint: Pubkey) -> Result<()> {
+        payment::allow_mint(ctx, mint)
+    }
+    
+    pub fn disallow_mint(ctx: Context<ManageMintAllowlist>, mint: Pubkey) -> Result<()> {
+        payment::disallow_mint(ctx, mint)
+    }
+    
+    pub fn execute_mint_proposal(ctx: Context<ExecuteMintProposal>) -> Result<()> {
+        payment::execute_mint_proposal(ctx)
+    }
+    
+    pub fn purchase_receivable(ctx: Context<PurchaseReceivable>, purchase_price: u64) -> Result<()> {
+        factoring::purchase_receivable(ctx, purchase_price)
+    }
+    
+    pub fn initialize_insurance_pool(
+        ctx: Context<InitializeInsurancePool>,
+        premium_bps: u16,
+        coverage_bps: [u16; CLAIM_REASON_COUNT],
+    ) -> Result<()> {
+        insurance::initialize_insurance_pool(ctx, premium_bps, coverage_bps)
+    }
+    
+    pub fn update_insurance_pool(
+        ctx: Context<UpdateInsurancePool>,
+        premium_bps: u16,
+        coverage_bps: [u16; CLAIM_REASON_COUNT],
+    ) -> Result<()> {
+        insurance::update_insurance_pool(ctx, premium_bps, coverage_bps)
+    }
+    
+    pub fn insure_batch(ctx: Context<InsureBatch>) -> Result<()> {
+        insurance::insure_batch(ctx)
+    }
+    
+    pub fn claim_insurance(ctx: Context<ClaimInsurance>, reason: ClaimReason) -> Result<()> {
+        insurance::claim_insurance(ctx, reason)
+    }
+    
+    pub fn underwrite_insurance(ctx: Context<Underwrite>, amount: u64) -> Result<()> {
+        insurance::underwrite_insurance(ctx, amount)
+    }
+    
+    pub fn withdraw_underwriting(ctx: Context<WithdrawUnderwriting>, shares: u64) -> Result<()> {
+        insurance::withdraw_underwriting(ctx, shares)
+    }
+    
+    pub fn request_underwriting_withdrawal(ctx: Context<RequestUnderwritingWithdrawal>, shares: u64) -> Result<()> {
+        insurance::request_underwriting_withdrawal(ctx, shares)
+    }
+    
+    pub fn initialize_treasury(
+        ctx: Context<InitializeTreasury>,
+        settlement_fee_bps: u16,
+        bond_fee_bps: u16,
+    ) -> Result<()> {
+        treasury::initialize_treasury(ctx, settlement_fee_bps, bond_fee_bps)
+    }
+    
+    pub fn update_treasury_fees(
+        ctx: Context<UpdateTreasuryFees>,
+        settlement_fee_bps: u16,
+        bond_fee_bps: u16,
+    ) -> Result<()> {
+        treasury::update_treasury_fees(ctx, settlement_fee_bps, bond_fee_bps)
+    }
+    
+    pub fn open_treasury_account(ctx: Context<OpenTreasuryAccount>) -> Result<()> {
+        treasury::open_treasury_account(ctx)
+    }
+    
+    pub fn execute_treasury_proposal(ctx: Context<ExecuteTreasuryProposal>) -> Result<()> {
+        treasury::execute_treasury_proposal(ctx)
+    }
+    
+    pub fn execute_treasury_sol_proposal(ctx: Context<ExecuteTreasurySolProposal>) -> Result<()> {
+        treasury::execute_treasury_sol_proposal(ctx)
+    }
+    
+    pub fn raise_dispute_sol(
+        ctx: Context<RaiseDisputeSol>,
+        reason: DisputeReason,
+        description: String,
+    ) -> Result<()> {
+        dispute::raise_dispute_sol(ctx, reason, description)
+    }
+    
+    pub fn answer_dispute_sol(ctx: Context<AnswerDisputeSol>) -> Result<()> {
+        dispute::answer_dispute_sol(ctx)
+    }
+    
+    pub fn appeal_dispute_sol(ctx: Context<AppealDisputeSol>) -> Result<()> {
+        dispute::appeal_dispute_sol(ctx)
+    }
+    
+    pub fn finalize_dispute_sol(ctx: Context<FinalizeDisputeSol>) -> Result<()> {
+        dispute::finalize_dispute_sol(ctx)
+    }
+    
+    pub fn claim_arbitration_fees_sol(ctx: Context<ClaimArbitrationFeesSol>) -> Result<()> {
+        arbitrator::claim_arbitration_fees_sol(ctx)
+    }
+    
+    pub fn claim_inspection_fees_sol(ctx: Context<ClaimInspectionFeesSol>) -> Result<()> {
+        inspection::claim_inspection_fees_sol(ctx)
+    }
 }
\ No newline at end of file
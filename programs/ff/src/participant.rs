@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::arbitrator::ArbitratorRegistry;
+use crate::reputation::Reputation;
 
 /// Defines the different participant roles.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -8,6 +10,31 @@ pub enum ParticipantRole {
     Wholesaler,
     Retailer,
     Arbitrator, // For dispute resolution and governance
+    Inspector,  // Independent quality inspection; must be certified to inspect
+    LiquidityProvider, // Buys farmers' receivables on picked-up batches
+}
+
+/// Number of role slots reserved in per-role tables (leaves room for new roles).
+pub const ROLE_COUNT: usize = 8;
+
+impl ParticipantRole {
+    /// Position of the role in per-role tables such as governance weights.
+    pub fn index(&self) -> usize {
+        match self {
+            ParticipantRole::Farmer => 0,
+            ParticipantRole::Transporter => 1,
+            ParticipantRole::Wholesaler => 2,
+            ParticipantRole::Retailer => 3,
+            ParticipantRole::Arbitrator => 4,
+            ParticipantRole::Inspector => 5,
+            ParticipantRole::LiquidityProvider => 6,
+        }
+    }
+
+    /// Bit of the role in a role bitmask.
+    pub fn mask(&self) -> u8 {
+        1 << self.index()
+    }
 }
 
 /// Participant account that stores identity details.
@@ -18,10 +45,15 @@ pub struct Participant {
     pub name: String,         // (max ~32 characters)
     pub contact_info: String, // (max ~64 characters)
     pub created_at: i64,
+    pub delegate: Pubkey,         // Pubkey::default() when voting directly
+    pub delegated_by_role: [u16; ROLE_COUNT], // delegators to this participant, per role
+    pub vote_locked_until: i64,   // latest voting deadline among proposals voted on
 }
 
 impl Participant {
     // Size: Increased to 256 bytes for buffer and safety (previously updated).
+    // owner (32) + role (1) + name (4+32) + contact_info (4+64) + created_at (8)
+    // + delegate (32) + delegated_by_role (16) + vote_locked_until (8) = 201.
     pub const LEN: usize = 256;
 }
 
@@ -34,7 +66,8 @@ pub enum ErrorCode {
     ContactInfoTooLong,
 }
 
-/// Registers a participant.
+/// Registers a participant. The Arbitrator role is reserved for wallets
+/// appointed to the arbitrator registry.
 pub fn register_participant(
     ctx: Context<RegisterParticipant>,
     role: ParticipantRole,
@@ -48,6 +81,9 @@ pub fn register_participant(
     if contact_info.len() > 64 {
         return Err(ErrorCode::ContactInfoTooLong.into());
     }
+    if role == ParticipantRole::Arbitrator && !ctx.accounts.registry.is_active(ctx.accounts.user.key) {
+        return Err(crate::error::ErrorCode::ArbitratorNotActive.into());
+    }
 
     let participant = &mut ctx.accounts.participant;
     participant.owner = ctx.accounts.user.key();
@@ -55,6 +91,14 @@ pub fn register_participant(
     participant.name = name;
     participant.contact_info = contact_info;
     participant.created_at = Clock::get()?.unix_timestamp;
+    participant.delegate = Pubkey::default();
+    participant.delegated_by_role = [0; ROLE_COUNT];
+    participant.vote_locked_until = 0;
+
+    let reputation = &mut ctx.accounts.reputation;
+    reputation.owner = ctx.accounts.user.key();
+    reputation.updated_at = participant.created_at;
+    reputation.bump = ctx.bumps.reputation;
     Ok(())
 }
 
@@ -69,6 +113,16 @@ pub struct RegisterParticipant<'info> {
         bump
     )]
     pub participant: Account<'info, Participant>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + Reputation::LEN,
+        seeds = [b"reputation", user.key.as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+    #[account(seeds = [b"arbitrator_registry"], bump = registry.bump)]
+    pub registry: Account<'info, ArbitratorRegistry>,
     #[account(mut)]
     pub user: Signer<'info>,
     pub system_program: Program<'info, System>,
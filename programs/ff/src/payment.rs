@@ -1,18 +1,159 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::error::ErrorCode;
-use crate::produce::ProduceStatus;
+use crate::governance::{GovernanceConfig, GovernanceProposal, ProposalAction};
+use crate::inspection::{QualityConfig, MAX_INSPECTIONS};
+use crate::invoice::*;
+use crate::treasury::{protocol_fee, ProtocolFeeCollected, Treasury};
+use crate::produce::{Produce, ProduceStatus};
 
 pub const QUALITY_THRESHOLD_HIGH: u8 = 80;
 pub const QUALITY_THRESHOLD_LOW: u8 = 50;
 pub const TEMP_THRESHOLD: i16 = 30;
 pub const HUMIDITY_THRESHOLD: u8 = 90;
+pub const MAX_ALLOWED_MINTS: usize = 8;
+/// Settlement "mint" recorded for batches escrowed in native SOL. It must be
+/// on the allowlist for SOL funding to be accepted.
+pub const NATIVE_SOL: Pubkey = anchor_spl::token::spl_token::native_mint::ID;
+pub const MILESTONE_COUNT: usize = 3;
+/// Without a schedule the whole price is released at delivery.
+pub const DEFAULT_MILESTONE_BPS: [u16; MILESTONE_COUNT] = [0, 10_000, 0];
 
+/// Points in a batch's journey at which part of the escrow is released.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Milestone {
+    Pickup,
+    Delivery,
+    Quality,
+}
+
+impl Milestone {
+    pub fn index(&self) -> usize {
+        match self {
+            Milestone::Pickup => 0,
+            Milestone::Delivery => 1,
+            Milestone::Quality => 2,
+        }
+    }
+
+    pub fn mask(&self) -> u8 {
+        1 << self.index()
+    }
+
+    /// Whether the batch has progressed far enough to release this milestone.
+    pub fn reached(&self, produce: &Produce) -> bool {
+        match self {
+            Milestone::Pickup => produce.pickup_confirmed,
+            Milestone::Delivery => produce.delivery_confirmed,
+            Milestone::Quality => {
+                produce.delivery_confirmed && produce.status == ProduceStatus::QualityVerified
+            }
+        }
+    }
+}
+
+/// Signs for every batch escrow account.
 #[account]
 pub struct Vault {
     pub bump: u8,
 }
 
+/// Program-owned account holding a native SOL batch's escrowed lamports.
+/// Everything above its rent-exempt minimum belongs to the batch.
+#[account]
+pub struct SolEscrow {
+    pub produce: Pubkey,
+    pub bump: u8,
+}
+
+impl SolEscrow {
+    // Size: produce (32) + bump (1) = 33.
+    pub const LEN: usize = 33;
+}
+
+/// Stablecoin mints batches may be settled in, managed by governance.
+#[account]
+pub struct MintAllowlist {
+    pub mints: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl MintAllowlist {
+    // Size: mints (4 + 32 * MAX_ALLOWED_MINTS) + bump (1) = 261.
+    pub const LEN: usize = 4 + 32 * MAX_ALLOWED_MINTS + 1;
+
+    pub fn is_allowed(&self, mint: &Pubkey) -> bool {
+        self.mints.contains(mint)
+    }
+
+    fn allow(&mut self, mint: Pubkey) -> Result<()> {
+        if self.is_allowed(&mint) {
+            return Ok(());
+        }
+        require!(self.mints.len() < MAX_ALLOWED_MINTS, ErrorCode::MintAllowlistFull);
+        self.mints.push(mint);
+        Ok(())
+    }
+
+    fn disallow(&mut self, mint: &Pubkey) {
+        self.mints.retain(|allowed| allowed != mint);
+    }
+}
+
+/// Program-wide settlement policy, managed by the governance admin.
+#[account]
+pub struct PaymentPolicy {
+    pub shrinkage_tolerance_bps: u16, // transit loss the farmer bears before the transporter is charged
+    pub bump: u8,
+}
+
+impl PaymentPolicy {
+    // Size: shrinkage_tolerance_bps (2) + bump (1) = 3.
+    pub const LEN: usize = 3;
+}
+
+#[event]
+pub struct MintAllowlistChanged {
+    pub mint: Pubkey,
+    pub allowed: bool,
+    pub timestamp: i64,
+}
+
+/// Tracks how many tokens a wallet has locked in the stake vault.
+#[account]
+pub struct StakeAccount {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+    pub locked_amount: u64, // backs open proposals; cannot leave the vault before locked_until
+    pub locked_until: i64,  // latest voting deadline among the proposals it backs
+}
+
+impl StakeAccount {
+    // Size: owner (32) + amount (8) + bump (1) + locked_amount (8) + locked_until (8) = 57.
+    pub const LEN: usize = 57;
+
+    /// Locks `amount` of the stake until `until`, on top of any lock still in force.
+    pub fn lock(&mut self, amount: u64, until: i64, now: i64) {
+        if now >= self.locked_until {
+            self.locked_amount = 0;
+        }
+        self.locked_amount = self.locked_amount.max(amount);
+        self.locked_until = self.locked_until.max(until);
+    }
+
+    /// Takes `amount` out of the stake, keeping any locked amount in place.
+    pub fn withdraw(&mut self, amount: u64, now: i64) -> Result<()> {
+        require!(self.amount >= amount, ErrorCode::InsufficientFunds);
+        let locked = if now < self.locked_until { self.locked_amount } else { 0 };
+        require!(self.amount - amount >= locked, ErrorCode::StakeLocked);
+        self.amount -= amount;
+        Ok(())
+    }
+}
+
 pub fn initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
     vault.bump = ctx.bumps.vault;
@@ -20,103 +161,366 @@ pub fn initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
     Ok(())
 }
 
-pub fn fund_vault(ctx: Context<FundVault>, amount: u64) -> Result<()> {
-    let produce = &ctx.accounts.produce;
-    let total_required = produce.farmer_price.saturating_add(produce.transporter_fee);
-    require!(amount >= total_required, ErrorCode::InsufficientFunds);
+/// Creates the settlement policy. Governance admin only.
+pub fn initialize_payment_policy(
+    ctx: Context<InitializePaymentPolicy>,
+    shrinkage_tolerance_bps: u16,
+) -> Result<()> {
+    require!(shrinkage_tolerance_bps <= 10_000, ErrorCode::InvalidConfig);
+    let payment_policy = &mut ctx.accounts.payment_policy;
+    payment_policy.shrinkage_tolerance_bps = shrinkage_tolerance_bps;
+    payment_policy.bump = ctx.bumps.payment_policy;
+    Ok(())
+}
 
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.retailer_token_account.to_account_info(),
-        to: ctx.accounts.payment_vault.to_account_info(),
-        authority: ctx.accounts.retailer.to_account_info(),
+/// Updates the settlement policy. Governance admin only.
+pub fn update_payment_policy(
+    ctx: Context<UpdatePaymentPolicy>,
+    shrinkage_tolerance_bps: u16,
+) -> Result<()> {
+    require!(shrinkage_tolerance_bps <= 10_000, ErrorCode::InvalidConfig);
+    ctx.accounts.payment_policy.shrinkage_tolerance_bps = shrinkage_tolerance_bps;
+    Ok(())
+}
+
+/// Creates the (empty) mint allowlist. Governance admin only.
+pub fn initialize_mint_allowlist(ctx: Context<InitializeMintAllowlist>) -> Result<()> {
+    let mint_allowlist = &mut ctx.accounts.mint_allowlist;
+    mint_allowlist.mints = Vec::new();
+    mint_allowlist.bump = ctx.bumps.mint_allowlist;
+    Ok(())
+}
+
+/// Adds a mint to the allowlist. Governance admin only.
+pub fn allow_mint(ctx: Context<ManageMintAllowlist>, mint: Pubkey) -> Result<()> {
+    ctx.accounts.mint_allowlist.allow(mint)?;
+    emit!(MintAllowlistChanged {
+        mint,
+        allowed: true,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Removes a mint from the allowlist. Batches already settling in it are
+/// unaffected. Governance admin only.
+pub fn disallow_mint(ctx: Context<ManageMintAllowlist>, mint: Pubkey) -> Result<()> {
+    ctx.accounts.mint_allowlist.disallow(&mint);
+    emit!(MintAllowlistChanged {
+        mint,
+        allowed: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Applies a queued mint allowlist proposal once its timelock has elapsed.
+/// Anyone may call this.
+pub fn execute_mint_proposal(ctx: Context<ExecuteMintProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let mint_allowlist = &mut ctx.accounts.mint_allowlist;
+    let (mint, allowed) = match proposal.action {
+        ProposalAction::AllowMint { mint } => {
+            mint_allowlist.allow(mint)?;
+            (mint, true)
+        }
+        ProposalAction::DisallowMint { mint } => {
+            mint_allowlist.disallow(&mint);
+            (mint, false)
+        }
+        _ => return Err(ErrorCode::ProposalActionMismatch.into()),
     };
-    token::transfer(
-        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
-        amount,
-    )?;
+    proposal.begin_execution()?;
+
+    emit!(MintAllowlistChanged {
+        mint,
+        allowed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
 
-pub fn process_payment(ctx: Context<ProcessPayment>) -> Result<()> {
-    let produce = &ctx.accounts.produce;
+/// Credits a deposit to the batch; the first one picks the settlement mint
+/// and the buyer.
+fn record_deposit(
+    produce: &mut Produce,
+    depositor: Pubkey,
+    mint: Pubkey,
+    mint_allowlist: &MintAllowlist,
+    treasury: &Treasury,
+    quality_config: &QualityConfig,
+    amount: u64,
+) -> Result<()> {
     require!(
-        produce.delivery_confirmed && produce.status == ProduceStatus::Delivered,
+        !produce.settled && produce.milestones_released & Milestone::Quality.mask() == 0,
         ErrorCode::InvalidStatus
     );
-
-    let mut farmer_reward = produce.farmer_price;
-    let mut transporter_reward = produce.transporter_fee;
-
-    if produce.verified_quality >= QUALITY_THRESHOLD_HIGH {
-        farmer_reward = farmer_reward.saturating_add(farmer_reward / 5); // +20%
-        transporter_reward = transporter_reward.saturating_add(transporter_reward / 10); // +10%
-    } else if produce.verified_quality < QUALITY_THRESHOLD_LOW && !produce.dispute_raised {
-        farmer_reward = farmer_reward.saturating_sub(farmer_reward * 3 / 10); // -30%
-        transporter_reward = transporter_reward.saturating_sub(transporter_reward * 15 / 100); // -15%
+    if produce.settlement_mint == Pubkey::default() {
+        require!(mint_allowlist.is_allowed(&mint), ErrorCode::MintNotAllowed);
+        produce.settlement_mint = mint;
+        produce.protocol_fee_bps = treasury.settlement_fee_bps;
+        produce.inspection_fee = quality_config.inspection_fee;
     }
+    require!(produce.settlement_mint == mint, ErrorCode::MintMismatch);
 
-    if produce.transport_temp != -999 && produce.transport_temp > TEMP_THRESHOLD {
-        transporter_reward = transporter_reward.saturating_sub(transporter_reward / 5); // -20%
-    }
-    if produce.transport_humidity != 255 && produce.transport_humidity > HUMIDITY_THRESHOLD {
-        transporter_reward = transporter_reward.saturating_sub(transporter_reward / 10); // -10%
+    // The first funder becomes the batch's buyer; refunds go back to them.
+    if produce.buyer == Pubkey::default() {
+        produce.buyer = depositor;
     }
+    require!(produce.buyer == depositor, ErrorCode::Unauthorized);
+    produce.escrowed_amount = produce.escrowed_amount.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+    Ok(())
+}
 
-    if produce.dispute_raised {
-        return Ok(()); // Payments deferred until dispute resolution
+/// Transfer fee settings of a Token-2022 mint, if it has any.
+fn transfer_fee_config(mint: &InterfaceAccount<Mint>) -> Option<TransferFeeConfig> {
+    let info = mint.to_account_info();
+    if *info.owner != anchor_spl::token_2022::ID {
+        return None;
     }
+    token_interface::get_mint_extension_data::<TransferFeeConfig>(&info).ok()
+}
 
-    let min_reward = 10u64;
-    farmer_reward = farmer_reward.max(min_reward);
-    transporter_reward = transporter_reward.max(min_reward);
+/// Fee the mint withholds when `amount` is transferred.
+pub fn transfer_fee(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    match transfer_fee_config(mint) {
+        Some(config) => Ok(config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(ErrorCode::Overflow)?),
+        None => Ok(0),
+    }
+}
 
-    // Get the vault PDA, which should be consistent with the one used during initialization
-    let vault_bump = ctx.bumps.payment_vault;
-    let bump_array = [vault_bump];
-    let vault_seeds = &[b"vault".as_ref(), &bump_array][..];
-    let signer = &[&vault_seeds[..]];
+/// Amount to send so that the recipient receives `net` after the mint's
+/// transfer fee.
+pub fn gross_up(mint: &InterfaceAccount<Mint>, net: u64) -> Result<u64> {
+    match transfer_fee_config(mint) {
+        Some(config) => {
+            let fee = config
+                .calculate_inverse_epoch_fee(Clock::get()?.epoch, net)
+                .ok_or(ErrorCode::Overflow)?;
+            Ok(net.checked_add(fee).ok_or(ErrorCode::Overflow)?)
+        }
+        None => Ok(net),
+    }
+}
 
-    let cpi_accounts_farmer = Transfer {
-        from: ctx.accounts.payment_vault.to_account_info(),
-        to: ctx.accounts.farmer_payment_account.to_account_info(),
-        authority: ctx.accounts.payment_vault.to_account_info(),
-    };
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            cpi_accounts_farmer,
-            signer,
-        ),
-        farmer_reward,
+/// Deposits a buyer's payment into the batch escrow. Only the amount that
+/// reaches the escrow after any transfer fee is credited to the batch.
+pub fn fund_vault(ctx: Context<FundVault>, amount: u64) -> Result<()> {
+    let received = amount
+        .checked_sub(transfer_fee(&ctx.accounts.mint, amount)?)
+        .ok_or(ErrorCode::Overflow)?;
+    // Wrapped SOL would be mistaken for a native SOL batch; use fund_vault_sol.
+    require!(ctx.accounts.mint.key() != NATIVE_SOL, ErrorCode::MintNotAllowed);
+    let produce = &mut ctx.accounts.produce;
+    record_deposit(
+        produce,
+        ctx.accounts.retailer.key(),
+        ctx.accounts.mint.key(),
+        &ctx.accounts.mint_allowlist,
+        &ctx.accounts.treasury,
+        &ctx.accounts.quality_config,
+        received,
     )?;
+    require!(
+        produce.escrowed_amount >= required_escrow(produce, Some(&ctx.accounts.mint))?,
+        ErrorCode::InsufficientFunds
+    );
 
-    let cpi_accounts_transporter = Transfer {
-        from: ctx.accounts.payment_vault.to_account_info(),
-        to: ctx.accounts.transporter_payment_account.to_account_info(),
-        authority: ctx.accounts.payment_vault.to_account_info(),
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.retailer_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.escrow.to_account_info(),
+        authority: ctx.accounts.retailer.to_account_info(),
     };
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            cpi_accounts_transporter,
-            signer,
-        ),
-        transporter_reward,
+    token_interface::transfer_checked(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+        amount,
+        ctx.accounts.mint.decimals,
     )?;
+    Ok(())
+}
 
+/// Final accounting of a batch. Adjustments are the quality and cold-chain
+/// bonuses (positive) or penalties (negative) applied to the base amounts;
+/// fees are reported net of any transfer fee.
+#[event]
+pub struct SettlementCompleted {
+    pub produce: Pubkey,
+    pub buyer: Pubkey,
+    pub farmer_base: u64,
+    pub transporter_base: u64,
+    pub transit_loss_charge: u64,
+    pub farmer_adjustment: i64,
+    pub transporter_adjustment: i64,
+    pub farmer_paid: u64,
+    pub transporter_paid: u64,
+    pub protocol_fees: u64,
+    pub buyer_refund: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneReleased {
+    pub produce: Pubkey,
+    pub milestone: Milestone,
+    pub farmer_amount: u64,
+    pub transporter_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Sets the share of the farmer price and transporter fee released at each
+/// milestone. Only possible before the batch is funded, so the buyer agrees
+/// to the schedule by funding it.
+pub fn set_milestone_schedule(
+    ctx: Context<SetPaymentTerms>,
+    milestone_bps: [u16; MILESTONE_COUNT],
+) -> Result<()> {
+    let produce = &mut ctx.accounts.produce;
+    require!(produce.buyer == Pubkey::default(), ErrorCode::InvalidStatus);
+    let total: u32 = milestone_bps.iter().map(|bps| *bps as u32).sum();
+    require!(total == 10_000, ErrorCode::InvalidMilestoneSchedule);
+    produce.milestone_bps = milestone_bps;
     Ok(())
 }
 
-// New function to process payments from ConfirmDelivery context
-pub fn process_delivery_payment(ctx: Context<crate::produce::ConfirmDelivery>) -> Result<()> {
-    let produce = &ctx.accounts.produce;
+/// Prices the batch per unit of quantity, replacing the lump-sum farmer
+/// price. Only possible before the batch is funded.
+pub fn set_unit_price(ctx: Context<SetPaymentTerms>, price_per_unit: u64) -> Result<()> {
+    let produce = &mut ctx.accounts.produce;
+    require!(produce.buyer == Pubkey::default(), ErrorCode::InvalidStatus);
+    produce.farmer_price = price_per_unit
+        .checked_mul(produce.quantity)
+        .ok_or(ErrorCode::Overflow)?;
+    produce.price_per_unit = price_per_unit;
+    Ok(())
+}
+
+/// Net farmer and transporter amounts due at a milestone, marking it released.
+/// Returns `None` while a dispute defers payment.
+fn take_milestone(produce: &mut Produce, milestone: Milestone) -> Result<Option<(u64, u64)>> {
+    require!(milestone.reached(produce) && !produce.settled, ErrorCode::InvalidStatus);
     require!(
-        produce.delivery_confirmed && produce.status == ProduceStatus::Delivered,
-        ErrorCode::InvalidStatus
+        produce.milestones_released & milestone.mask() == 0,
+        ErrorCode::MilestoneAlreadyReleased
     );
 
-    let mut farmer_reward = produce.farmer_price;
-    let mut transporter_reward = produce.transporter_fee;
+    let (farmer_amount, transporter_amount) = match milestone {
+        Milestone::Quality => {
+            let Some((farmer_total, transporter_total)) = compute_payouts(produce) else {
+                return Ok(None);
+            };
+            (
+                farmer_total.saturating_sub(produce.farmer_released),
+                transporter_total.saturating_sub(produce.transporter_released),
+            )
+        }
+        _ => {
+            if produce.dispute_raised {
+                return Ok(None);
+            }
+            let (farmer_base, transporter_base) = base_payouts(produce);
+            let bps: u128 = produce.milestone_bps[..=milestone.index()]
+                .iter()
+                .map(|bps| *bps as u128)
+                .sum();
+            (
+                ((farmer_base as u128 * bps / 10_000) as u64).saturating_sub(produce.farmer_released),
+                ((transporter_base as u128 * bps / 10_000) as u64)
+                    .saturating_sub(produce.transporter_released),
+            )
+        }
+    };
+
+    produce.milestones_released |= milestone.mask();
+    produce.farmer_released = produce.farmer_released.saturating_add(farmer_amount);
+    produce.transporter_released = produce.transporter_released.saturating_add(transporter_amount);
+    Ok(Some((farmer_amount, transporter_amount)))
+}
+
+/// Farmer and transporter amounts for the quantity delivered, before quality
+/// adjustments.
+pub fn base_payouts(produce: &Produce) -> (u64, u64) {
+    if !produce.delivery_confirmed {
+        return (produce.farmer_price, produce.transporter_fee);
+    }
+    let loss_charge = produce.transit_loss_charge.min(produce.transporter_fee);
+    (
+        produce.price_of(produce.received_quantity).saturating_add(loss_charge),
+        produce.transporter_fee - loss_charge,
+    )
+}
+
+/// Largest payouts `compute_payouts` can produce.
+pub fn max_payouts(produce: &Produce) -> (u64, u64) {
+    let min_reward = 10u64;
+    (
+        produce.farmer_price.saturating_add(produce.farmer_price / 5).max(min_reward),
+        produce.transporter_fee.saturating_add(produce.transporter_fee / 10).max(min_reward),
+    )
+}
+
+/// Escrow a batch must hold once funded: the largest payouts, each milestone
+/// transfer grossed up for the mint's transfer fee on its own, the treasury
+/// fee on top, and the inspection fees of a full set of inspections.
+pub fn required_escrow(produce: &Produce, mint: Option<&InterfaceAccount<Mint>>) -> Result<u64> {
+    let (farmer_max, transporter_max) = max_payouts(produce);
+    let mut total: u64 = 0;
+    for payout_max in [farmer_max, transporter_max] {
+        // Milestones before quality release their share of the schedule; the
+        // quality milestone pays the rest, bonus included.
+        let mut remaining = payout_max;
+        for milestone in [Milestone::Pickup, Milestone::Delivery, Milestone::Quality] {
+            let amount = if milestone == Milestone::Quality {
+                remaining
+            } else {
+                ((payout_max as u128 * produce.milestone_bps[milestone.index()] as u128 / 10_000) as u64)
+                    .min(remaining)
+            };
+            remaining -= amount;
+            let gross = match mint {
+                Some(mint) if amount > 0 => gross_up(mint, amount)?,
+                _ => amount,
+            };
+            total = total.checked_add(gross).ok_or(ErrorCode::Overflow)?;
+        }
+    }
+    let inspection_fees = produce
+        .inspection_fee
+        .checked_mul(MAX_INSPECTIONS as u64)
+        .ok_or(ErrorCode::Overflow)?;
+    let total = total
+        .checked_add(protocol_fee(farmer_max.saturating_add(transporter_max), produce.protocol_fee_bps))
+        .and_then(|total| total.checked_add(inspection_fees))
+        .ok_or(ErrorCode::Overflow)?;
+    Ok(total)
+}
+
+/// Breakdown of a settled batch, once the quality milestone has paid out and
+/// the unspent escrow has been refunded.
+pub fn settlement_breakdown(produce_key: Pubkey, produce: &Produce, buyer_refund: u64) -> Result<SettlementCompleted> {
+    let (farmer_base, transporter_base) = base_payouts(produce);
+    Ok(SettlementCompleted {
+        produce: produce_key,
+        buyer: produce.buyer,
+        farmer_base,
+        transporter_base,
+        transit_loss_charge: produce.transit_loss_charge.min(produce.transporter_fee),
+        farmer_adjustment: produce.farmer_released as i64 - farmer_base as i64,
+        transporter_adjustment: produce.transporter_released as i64 - transporter_base as i64,
+        farmer_paid: produce.farmer_released,
+        transporter_paid: produce.transporter_released,
+        protocol_fees: produce.protocol_fees,
+        buyer_refund,
+        timestamp: Clock::get()?.unix_timestamp,
+    })
+}
+
+/// Farmer and transporter payouts after quality and cold-chain adjustments,
+/// or `None` while a dispute defers payment. Shared by every settlement path.
+pub fn compute_payouts(produce: &Produce) -> Option<(u64, u64)> {
+    let (mut farmer_reward, mut transporter_reward) = base_payouts(produce);
 
     if produce.verified_quality >= QUALITY_THRESHOLD_HIGH {
         farmer_reward = farmer_reward.saturating_add(farmer_reward / 5); // +20%
@@ -134,83 +538,314 @@ pub fn process_delivery_payment(ctx: Context<crate::produce::ConfirmDelivery>) -
     }
 
     if produce.dispute_raised {
-        return Ok(()); // Payments deferred until dispute resolution
+        return None; // Payments deferred until dispute resolution
     }
 
     let min_reward = 10u64;
     farmer_reward = farmer_reward.max(min_reward);
     transporter_reward = transporter_reward.max(min_reward);
 
-    // Use the vault bump from the context
-    let vault_bump = ctx.bumps.vault;  // Use vault bump instead of payment_vault
-    let bump_array = [vault_bump];
-    let vault_seeds = &[b"vault".as_ref(), &bump_array][..];
-    let signer = &[&vault_seeds[..]];
+    Some((farmer_reward, transporter_reward))
+}
 
-    // Transfer to farmer
-    let cpi_accounts_farmer = Transfer {
-        from: ctx.accounts.payment_vault.to_account_info(),
-        to: ctx.accounts.farmer_payment_account.to_account_info(),
-        authority: ctx.accounts.vault.to_account_info(),  // Use vault PDA as authority
+/// Settles a batch: pays the quality milestone, refunds whatever the payouts
+/// did not use to the buyer and issues the batch's invoice.
+pub fn process_payment(ctx: Context<SettleBatch>) -> Result<()> {
+    let accounts = ctx.accounts;
+    require!(
+        pay_milestone(&mut accounts.payment, Milestone::Quality)?,
+        ErrorCode::PaymentsDeferred
+    );
+
+    let payment = &mut accounts.payment;
+    let buyer_refund = payment.produce.escrowed_amount;
+    payment.produce.escrowed_amount = 0;
+    payment.produce.settled = true;
+    transfer_from_vault(
+        &payment.token_program,
+        &payment.escrow,
+        &payment.buyer_payment_account,
+        &payment.mint,
+        &payment.vault,
+        buyer_refund,
+    )?;
+
+    let settlement = settlement_breakdown(payment.produce.key(), &payment.produce, buyer_refund)?;
+    let treasury = accounts.payment.treasury_vault.key();
+    accounts
+        .invoicing
+        .issue(&ctx.bumps.invoicing, &accounts.payment.produce, &settlement, treasury)?;
+    emit!(settlement);
+    Ok(())
+}
+
+/// Releases the escrow share of a reached pickup or delivery milestone to the
+/// farmer and transporter. The quality milestone settles the batch through
+/// `process_payment`.
+pub fn release_milestone(ctx: Context<ProcessPayment>, milestone: Milestone) -> Result<()> {
+    require!(milestone != Milestone::Quality, ErrorCode::SettlementMilestone);
+    pay_milestone(ctx.accounts, milestone)?;
+    Ok(())
+}
+
+/// Pays the farmer and transporter their share of a milestone. Returns
+/// `false` when a dispute defers payment.
+fn pay_milestone(accounts: &mut ProcessPayment, milestone: Milestone) -> Result<bool> {
+    let produce = &mut accounts.produce;
+    let Some((farmer_amount, transporter_amount)) = take_milestone(produce, milestone)? else {
+        return Ok(false); // Payments deferred until dispute resolution
     };
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            cpi_accounts_farmer,
-            signer,
-        ),
-        farmer_reward,
+
+    // Recipients receive the full amount; the escrow covers any transfer fee
+    // and the treasury fee on top.
+    let farmer_gross = gross_up(&accounts.mint, farmer_amount)?;
+    let transporter_gross = gross_up(&accounts.mint, transporter_amount)?;
+    let treasury_fee = protocol_fee(farmer_amount.saturating_add(transporter_amount), produce.protocol_fee_bps);
+    produce.protocol_fees = produce.protocol_fees.checked_add(treasury_fee).ok_or(ErrorCode::Overflow)?;
+
+    // Payouts are limited to what was escrowed for this batch.
+    produce.escrowed_amount = farmer_gross
+        .checked_add(transporter_gross)
+        .and_then(|total| total.checked_add(treasury_fee))
+        .and_then(|total| produce.escrowed_amount.checked_sub(total))
+        .ok_or(ErrorCode::InsufficientFunds)?;
+    let produce_key = produce.key();
+
+    transfer_from_vault(
+        &accounts.token_program,
+        &accounts.escrow,
+        &accounts.farmer_payment_account,
+        &accounts.mint,
+        &accounts.vault,
+        farmer_gross,
+    )?;
+    transfer_from_vault(
+        &accounts.token_program,
+        &accounts.escrow,
+        &accounts.transporter_payment_account,
+        &accounts.mint,
+        &accounts.vault,
+        transporter_gross,
     )?;
+    transfer_from_vault(
+        &accounts.token_program,
+        &accounts.escrow,
+        &accounts.treasury_vault,
+        &accounts.mint,
+        &accounts.vault,
+        treasury_fee,
+    )?;
+    if treasury_fee > 0 {
+        emit!(ProtocolFeeCollected {
+            produce: produce_key,
+            mint: accounts.mint.key(),
+            amount: treasury_fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    emit!(MilestoneReleased {
+        produce: produce_key,
+        milestone,
+        farmer_amount,
+        transporter_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(true)
+}
+
+/// Deposits lamports into the batch's SOL escrow, for buyers settling in
+/// native SOL instead of a token.
+pub fn fund_vault_sol(ctx: Context<FundVaultSol>, amount: u64) -> Result<()> {
+    let produce = &mut ctx.accounts.produce;
+    record_deposit(
+        produce,
+        ctx.accounts.retailer.key(),
+        NATIVE_SOL,
+        &ctx.accounts.mint_allowlist,
+        &ctx.accounts.treasury,
+        &ctx.accounts.quality_config,
+        amount,
+    )?;
+    require!(
+        produce.escrowed_amount >= required_escrow(produce, None)?,
+        ErrorCode::InsufficientFunds
+    );
 
-    // Transfer to transporter
-    let cpi_accounts_transporter = Transfer {
-        from: ctx.accounts.payment_vault.to_account_info(),
-        to: ctx.accounts.transporter_payment_account.to_account_info(),
-        authority: ctx.accounts.vault.to_account_info(),  // Use vault PDA as authority
+    let sol_escrow = &mut ctx.accounts.sol_escrow;
+    sol_escrow.produce = produce.key();
+    sol_escrow.bump = ctx.bumps.sol_escrow;
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.retailer.to_account_info(),
+        to: ctx.accounts.sol_escrow.to_account_info(),
     };
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            cpi_accounts_transporter,
-            signer,
-        ),
-        transporter_reward,
+    system_program::transfer(
+        CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts),
+        amount,
+    )?;
+    Ok(())
+}
+
+/// Pays the farmer and transporter of a native SOL batch, applying the same
+/// quality and cold-chain adjustments as `process_payment`.
+pub fn process_payment_sol(ctx: Context<SettleBatchSol>) -> Result<()> {
+    let accounts = ctx.accounts;
+    require!(
+        pay_milestone_sol(&mut accounts.payment, Milestone::Quality)?,
+        ErrorCode::PaymentsDeferred
+    );
+
+    let payment = &mut accounts.payment;
+    let buyer_refund = payment.produce.escrowed_amount;
+    payment.produce.escrowed_amount = 0;
+    payment.produce.settled = true;
+    transfer_lamports(
+        &payment.sol_escrow.to_account_info(),
+        &payment.buyer.to_account_info(),
+        buyer_refund,
     )?;
 
+    let settlement = settlement_breakdown(payment.produce.key(), &payment.produce, buyer_refund)?;
+    let treasury = accounts.payment.treasury.key();
+    accounts
+        .invoicing
+        .issue(&ctx.bumps.invoicing, &accounts.payment.produce, &settlement, treasury)?;
+    emit!(settlement);
     Ok(())
 }
 
+/// Native SOL counterpart of `release_milestone`.
+pub fn release_milestone_sol(ctx: Context<ProcessPaymentSol>, milestone: Milestone) -> Result<()> {
+    require!(milestone != Milestone::Quality, ErrorCode::SettlementMilestone);
+    pay_milestone_sol(ctx.accounts, milestone)?;
+    Ok(())
+}
+
+fn pay_milestone_sol(accounts: &mut ProcessPaymentSol, milestone: Milestone) -> Result<bool> {
+    let produce = &mut accounts.produce;
+    let Some((farmer_amount, transporter_amount)) = take_milestone(produce, milestone)? else {
+        return Ok(false); // Payments deferred until dispute resolution
+    };
+
+    let treasury_fee = protocol_fee(farmer_amount.saturating_add(transporter_amount), produce.protocol_fee_bps);
+    produce.protocol_fees = produce.protocol_fees.checked_add(treasury_fee).ok_or(ErrorCode::Overflow)?;
+
+    // Payouts are limited to what was escrowed for this batch.
+    produce.escrowed_amount = farmer_amount
+        .checked_add(transporter_amount)
+        .and_then(|total| total.checked_add(treasury_fee))
+        .and_then(|total| produce.escrowed_amount.checked_sub(total))
+        .ok_or(ErrorCode::InsufficientFunds)?;
+    let produce_key = produce.key();
+
+    let sol_escrow = accounts.sol_escrow.to_account_info();
+    transfer_lamports(&sol_escrow, &accounts.farmer.to_account_info(), farmer_amount)?;
+    transfer_lamports(&sol_escrow, &accounts.transporter.to_account_info(), transporter_amount)?;
+    if treasury_fee > 0 {
+        transfer_lamports(&sol_escrow, &accounts.treasury.to_account_info(), treasury_fee)?;
+        emit!(ProtocolFeeCollected {
+            produce: produce_key,
+            mint: NATIVE_SOL,
+            amount: treasury_fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    emit!(MilestoneReleased {
+        produce: produce_key,
+        milestone,
+        farmer_amount,
+        transporter_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(true)
+}
+
+/// Moves lamports out of a program-owned account.
+pub fn transfer_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    from.sub_lamports(amount)?;
+    to.add_lamports(amount)?;
+    Ok(())
+}
+
+/// Transfers `amount` out of a batch escrow account, signed by the vault PDA.
+/// Any transfer fee is withheld from what the recipient receives.
+pub fn transfer_from_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    escrow: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    vault: &Account<'info, Vault>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let bump_array = [vault.bump];
+    let vault_seeds = &[b"vault".as_ref(), &bump_array][..];
+    let signer = &[vault_seeds];
+    let cpi_accounts = TransferChecked {
+        from: escrow.to_account_info(),
+        mint: mint.to_account_info(),
+        to: to.to_account_info(),
+        authority: vault.to_account_info(),
+    };
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer),
+        amount,
+        mint.decimals,
+    )
+}
+
 pub fn stake_tokens(ctx: Context<StakeTokens>, amount: u64) -> Result<()> {
-    let cpi_accounts = Transfer {
+    // Only the amount that reaches the stake vault counts as staked.
+    let received = amount
+        .checked_sub(transfer_fee(&ctx.accounts.mint, amount)?)
+        .ok_or(ErrorCode::Overflow)?;
+    let stake_account = &mut ctx.accounts.stake_account;
+    stake_account.owner = ctx.accounts.staker.key();
+    stake_account.amount = stake_account.amount.checked_add(received).ok_or(ErrorCode::Overflow)?;
+    stake_account.bump = ctx.bumps.stake_account;
+
+    let cpi_accounts = TransferChecked {
         from: ctx.accounts.staker_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
         to: ctx.accounts.stake_vault.to_account_info(),
         authority: ctx.accounts.staker.to_account_info(),
     };
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
         amount,
+        ctx.accounts.mint.decimals,
     )?;
     Ok(())
 }
 
 pub fn unstake_tokens(ctx: Context<UnstakeTokens>, amount: u64) -> Result<()> {
+    ctx.accounts
+        .stake_account
+        .withdraw(amount, Clock::get()?.unix_timestamp)?;
+
     let vault_bump = ctx.bumps.stake_vault;
     let bump_array = [vault_bump];
     let vault_seeds = &[b"stake_vault".as_ref(), &bump_array][..];
     let signer = &[&vault_seeds[..]];
 
-    let cpi_accounts = Transfer {
+    let cpi_accounts = TransferChecked {
         from: ctx.accounts.stake_vault.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
         to: ctx.accounts.staker_token_account.to_account_info(),
         authority: ctx.accounts.stake_vault.to_account_info(),
     };
-    token::transfer(
+    token_interface::transfer_checked(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             cpi_accounts,
             signer,
         ),
         amount,
+        ctx.accounts.mint.decimals,
     )?;
     Ok(())
 }
@@ -225,21 +860,86 @@ pub struct InitializeVault<'info> {
         bump
     )]
     pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeMintAllowlist<'info> {
     #[account(
         init,
-        payer = authority,
-        token::mint = mint,
-        token::authority = vault, // Vault PDA controls the token account
-        seeds = [b"vault_token"],
+        payer = admin,
+        space = 8 + MintAllowlist::LEN,
+        seeds = [b"mint_allowlist"],
         bump
     )]
-    pub payment_vault: Account<'info, TokenAccount>,
-    pub mint: Account<'info, Mint>,
+    pub mint_allowlist: Account<'info, MintAllowlist>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
     #[account(mut)]
-    pub authority: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePaymentPolicy<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PaymentPolicy::LEN,
+        seeds = [b"payment_policy"],
+        bump
+    )]
+    pub payment_policy: Account<'info, PaymentPolicy>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePaymentPolicy<'info> {
+    #[account(mut, seeds = [b"payment_policy"], bump = payment_policy.bump)]
+    pub payment_policy: Account<'info, PaymentPolicy>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ManageMintAllowlist<'info> {
+    #[account(mut, seeds = [b"mint_allowlist"], bump = mint_allowlist.bump)]
+    pub mint_allowlist: Account<'info, MintAllowlist>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMintProposal<'info> {
+    #[account(mut, seeds = [b"proposal", &proposal.proposal_id.to_le_bytes()], bump)]
+    pub proposal: Account<'info, GovernanceProposal>,
+    #[account(mut, seeds = [b"mint_allowlist"], bump = mint_allowlist.bump)]
+    pub mint_allowlist: Account<'info, MintAllowlist>,
+    pub executor: Signer<'info>,
 }
 
 #[derive(Accounts)]
@@ -252,15 +952,30 @@ pub struct FundVault<'info> {
     pub produce: Account<'info, crate::produce::Produce>,
     #[account(mut)]
     pub retailer: Signer<'info>,
-    #[account(mut)]
-    pub retailer_token_account: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = mint)]
+    pub retailer_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"mint_allowlist"], bump = mint_allowlist.bump)]
+    pub mint_allowlist: Account<'info, MintAllowlist>,
+    #[account(seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(seeds = [b"quality_config"], bump = quality_config.bump)]
+    pub quality_config: Account<'info, QualityConfig>,
+    #[account(seeds = [b"vault"], bump = vault.bump)]
+    pub vault: Account<'info, Vault>,
+    /// Holds this batch's funds in its settlement mint.
     #[account(
-        mut,
-        seeds = [b"vault_token"],
+        init_if_needed,
+        payer = retailer,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program,
+        seeds = [b"escrow", produce.key().as_ref(), mint.key().as_ref()],
         bump
     )]
-    pub payment_vault: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -271,37 +986,145 @@ pub struct ProcessPayment<'info> {
         bump
     )]
     pub produce: Account<'info, crate::produce::Produce>,
+    #[account(
+        mut,
+        constraint = farmer_payment_account.owner == produce.farmer_payee() @ ErrorCode::InvalidPayoutAccount
+    )]
+    pub farmer_payment_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = transporter_payment_account.owner == produce.transporter @ ErrorCode::InvalidPayoutAccount
+    )]
+    pub transporter_payment_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, constraint = buyer_payment_account.owner == produce.buyer @ ErrorCode::InvalidPayoutAccount)]
+    pub buyer_payment_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"vault"], bump = vault.bump)]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"escrow", produce.key().as_ref(), produce.settlement_mint.as_ref()],
+        bump
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = produce.settlement_mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, seeds = [b"treasury_vault", produce.settlement_mint.as_ref()], bump)]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SettleBatch<'info> {
+    pub payment: ProcessPayment<'info>,
+    pub invoicing: IssueInvoice<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaymentTerms<'info> {
+    #[account(
+        mut,
+        seeds = [b"produce", &produce.produce_id.to_le_bytes()[..]],
+        bump,
+        has_one = farmer @ ErrorCode::Unauthorized
+    )]
+    pub produce: Account<'info, Produce>,
+    pub farmer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FundVaultSol<'info> {
+    #[account(
+        mut,
+        seeds = [b"produce", &produce.produce_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub produce: Account<'info, Produce>,
     #[account(mut)]
-    pub farmer_payment_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub transporter_payment_account: Account<'info, TokenAccount>,
+    pub retailer: Signer<'info>,
+    #[account(seeds = [b"mint_allowlist"], bump = mint_allowlist.bump)]
+    pub mint_allowlist: Account<'info, MintAllowlist>,
+    #[account(seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(seeds = [b"quality_config"], bump = quality_config.bump)]
+    pub quality_config: Account<'info, QualityConfig>,
+    #[account(
+        init_if_needed,
+        payer = retailer,
+        space = 8 + SolEscrow::LEN,
+        seeds = [b"sol_escrow", produce.key().as_ref()],
+        bump
+    )]
+    pub sol_escrow: Account<'info, SolEscrow>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProcessPaymentSol<'info> {
     #[account(
         mut,
-        seeds = [b"vault_token"],
+        seeds = [b"produce", &produce.produce_id.to_le_bytes()[..]],
         bump
     )]
-    pub payment_vault: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub produce: Account<'info, Produce>,
+    #[account(mut, seeds = [b"sol_escrow", produce.key().as_ref()], bump = sol_escrow.bump)]
+    pub sol_escrow: Account<'info, SolEscrow>,
+    #[account(
+        mut,
+        constraint = farmer.key() == produce.farmer_payee() @ ErrorCode::InvalidPayoutAccount
+    )]
+    pub farmer: SystemAccount<'info>,
+    #[account(mut, address = produce.transporter @ ErrorCode::InvalidPayoutAccount)]
+    pub transporter: SystemAccount<'info>,
+    #[account(mut, address = produce.buyer @ ErrorCode::InvalidPayoutAccount)]
+    pub buyer: SystemAccount<'info>,
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+}
+
+#[derive(Accounts)]
+pub struct SettleBatchSol<'info> {
+    pub payment: ProcessPaymentSol<'info>,
+    pub invoicing: IssueInvoice<'info>,
 }
 
 #[derive(Accounts)]
 pub struct StakeTokens<'info> {
     #[account(mut)]
     pub staker: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + StakeAccount::LEN,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
     #[account(mut)]
-    pub staker_token_account: Account<'info, TokenAccount>,
+    pub staker_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(mut, seeds = [b"stake_vault"], bump)]
-    pub stake_vault: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = stake_vault.mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 pub struct UnstakeTokens<'info> {
     #[account(mut)]
     pub staker: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump = stake_account.bump,
+        constraint = stake_account.owner == staker.key()
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
     #[account(mut)]
-    pub staker_token_account: Account<'info, TokenAccount>,
+    pub staker_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(mut, seeds = [b"stake_vault"], bump)]
-    pub stake_vault: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = stake_vault.mint @ ErrorCode::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
\ No newline at end of file
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 use crate::error::ErrorCode;
 use crate::participant::Participant;
+use crate::reputation::{Reputation, ON_TIME_WINDOW};
+use crate::inspection::{QualityConfig, MAX_INSPECTIONS};
 use std::collections::BTreeMap;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::payment::{PaymentPolicy, DEFAULT_MILESTONE_BPS, MILESTONE_COUNT};
 
 /// Different states of a produce batch.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -35,10 +37,96 @@ pub struct Produce {
     pub qr_code_uri: String,
     pub farmer_price: u64,
     pub transporter_fee: u64,
+    pub transporter: Pubkey,    // set at pickup
+    pub buyer: Pubkey,          // set when the batch is first funded
+    pub escrowed_amount: u64,   // funds held in the vault for this batch
+    pub quality_verifier: Pubkey, // last participant to verify quality
+    pub dispute_count: u32,       // disputes ever raised; next dispute index
+    pub open_disputes: u32,
+    pub picked_up_at: i64,
+    pub inspection_scores: Vec<u8>, // scores of all inspections; verified_quality is their median
+    pub certified_inspections: u8,  // inspections by independent certified inspectors
+    pub inspector_scores: Vec<u8>,  // scores from Inspector-role inspections; these take precedence
+    pub settlement_mint: Pubkey,    // chosen by the first deposit; Pubkey::default() until funded
+    pub milestone_bps: [u16; MILESTONE_COUNT], // share of the price and fee released at each milestone
+    pub milestones_released: u8,    // bitmask of released milestones
+    pub farmer_released: u64,       // paid to the farmer so far
+    pub transporter_released: u64,  // paid to the transporter so far
+    pub price_per_unit: u64,        // farmer price per unit of quantity
+    pub received_quantity: u64,     // quantity the buyer received, set at delivery
+    pub transit_loss_charge: u64,   // value of losses beyond tolerance, charged to the transporter
+    pub inspection_fees: u64,       // paid out of the escrow to independent inspectors
+    pub receivable_owner: Pubkey,   // liquidity provider that bought the farmer's payouts, if any
+    pub protocol_fee_bps: u16,      // treasury settlement fee in force when the batch was funded
+    pub protocol_fees: u64,         // paid to the treasury out of the escrow
+    pub inspection_fee: u64,        // per-inspection fee in force when the batch was funded
+    pub disputed_amount: u64,       // escrow held by open disputes
+    pub settled: bool,              // escrow paid out by settlement or a dispute ruling
 }
 
 impl Produce {
-    pub const LEN: usize = 265;
+    // 265 + transporter (32) + buyer (32) + escrowed_amount (8) + quality_verifier (32)
+    // + dispute_count (4) + open_disputes (4) + picked_up_at (8)
+    // + inspection_scores (4 + MAX_INSPECTIONS) + certified_inspections (1)
+    // + inspector_scores (4 + MAX_INSPECTIONS) + settlement_mint (32)
+    // + milestone_bps (2 * MILESTONE_COUNT) + milestones_released (1) + farmer_released (8) + transporter_released (8)
+    // + price_per_unit (8) + received_quantity (8) + transit_loss_charge (8) + inspection_fees (8)
+    // + receivable_owner (32) + protocol_fee_bps (2) + protocol_fees (8) + inspection_fee (8)
+    // + disputed_amount (8) + settled (1)
+    pub const LEN: usize = 265 + 32 + 32 + 8 + 32 + 4 + 4 + 8 + (4 + MAX_INSPECTIONS) + 1
+        + (4 + MAX_INSPECTIONS) + 32
+        + 2 * MILESTONE_COUNT + 1 + 8 + 8
+        + 8 + 8 + 8 + 8
+        + 32 + 2 + 8 + 8
+        + 8 + 1;
+
+    /// Account the farmer's payouts go to: the liquidity provider once the
+    /// receivable has been sold, otherwise the farmer.
+    pub fn farmer_payee(&self) -> Pubkey {
+        if self.receivable_owner == Pubkey::default() {
+            self.farmer
+        } else {
+            self.receivable_owner
+        }
+    }
+
+    /// Farmer price of `quantity` units, pro rata to the batch price.
+    pub fn price_of(&self, quantity: u64) -> u64 {
+        if self.quantity == 0 {
+            return 0;
+        }
+        (self.farmer_price as u128 * quantity as u128 / self.quantity as u128) as u64
+    }
+
+    /// Holds the escrow no open dispute holds yet for a new dispute and
+    /// returns that share.
+    pub fn hold_for_dispute(&mut self) -> u64 {
+        let share = self.escrowed_amount.saturating_sub(self.disputed_amount);
+        self.disputed_amount += share;
+        share
+    }
+
+    /// Takes a settled dispute's share out of the escrow and returns the part
+    /// still held, which its ruling pays out.
+    pub fn release_dispute_share(&mut self, share: u64) -> u64 {
+        self.disputed_amount = self.disputed_amount.saturating_sub(share);
+        let releasable = share.min(self.escrowed_amount);
+        self.escrowed_amount -= releasable;
+        if releasable > 0 {
+            self.settled = true;
+        }
+        releasable
+    }
+
+    /// Whether `key` is a party to this shipment: the farmer, the assigned
+    /// transporter, the funding buyer or the quality verifier.
+    pub fn is_party(&self, key: &Pubkey) -> bool {
+        *key != Pubkey::default()
+            && (*key == self.farmer
+                || *key == self.transporter
+                || *key == self.buyer
+                || *key == self.quality_verifier)
+    }
 }
 
 #[event]
@@ -48,6 +136,14 @@ pub struct HarvestLogged {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct DeliveryReceived {
+    pub produce_id: u64,
+    pub received_quantity: u64,
+    pub transit_loss_charge: u64,
+    pub timestamp: i64,
+}
+
 pub fn log_harvest(
     ctx: Context<LogHarvest>,
     produce_id: u64,
@@ -84,6 +180,31 @@ pub fn log_harvest(
     produce.qr_code_uri = qr_code_uri;
     produce.farmer_price = farmer_price;
     produce.transporter_fee = transporter_fee;
+    produce.transporter = Pubkey::default();
+    produce.buyer = Pubkey::default();
+    produce.escrowed_amount = 0;
+    produce.quality_verifier = Pubkey::default();
+    produce.dispute_count = 0;
+    produce.open_disputes = 0;
+    produce.picked_up_at = 0;
+    produce.inspection_scores = Vec::new();
+    produce.certified_inspections = 0;
+    produce.inspector_scores = Vec::new();
+    produce.settlement_mint = Pubkey::default();
+    produce.milestone_bps = DEFAULT_MILESTONE_BPS;
+    produce.milestones_released = 0;
+    produce.farmer_released = 0;
+    produce.transporter_released = 0;
+    produce.price_per_unit = farmer_price.checked_div(quantity).unwrap_or(0);
+    produce.received_quantity = 0;
+    produce.transit_loss_charge = 0;
+    produce.inspection_fees = 0;
+    produce.receivable_owner = Pubkey::default();
+    produce.protocol_fee_bps = 0;
+    produce.protocol_fees = 0;
+    produce.inspection_fee = 0;
+    produce.disputed_amount = 0;
+    produce.settled = false;
 
     emit!(HarvestLogged {
         produce_id,
@@ -130,9 +251,15 @@ pub fn record_pickup(ctx: Context<RecordPickup>, temperature: i16, humidity: u8)
         ErrorCode::InvalidStatus
     );
     produce.status = ProduceStatus::PickedUp;
+    produce.transporter = ctx.accounts.transporter.key();
     produce.last_updated = Clock::get()?.unix_timestamp;
+    produce.picked_up_at = produce.last_updated;
     produce.transport_temp = temperature;
     produce.transport_humidity = humidity;
+
+    ctx.accounts
+        .transporter_reputation
+        .record_transport_temp(temperature, produce.last_updated);
     Ok(())
 }
 
@@ -151,10 +278,17 @@ pub struct RecordPickup<'info> {
         constraint = transporter_account.owner == transporter.key()
     )]
     pub transporter_account: Account<'info, Participant>,
+    #[account(mut, seeds = [b"reputation", transporter.key.as_ref()], bump = transporter_reputation.bump)]
+    pub transporter_reputation: Account<'info, Reputation>,
     pub transporter: Signer<'info>,
 }
 
 pub fn confirm_pickup(ctx: Context<ConfirmPickup>) -> Result<()> {
+    if let crate::participant::ParticipantRole::Farmer = ctx.accounts.farmer_account.role {
+        // OK.
+    } else {
+        return Err(ErrorCode::Unauthorized.into());
+    }
     let produce = &mut ctx.accounts.produce;
     require!(
         produce.status == ProduceStatus::PickedUp,
@@ -170,7 +304,8 @@ pub struct ConfirmPickup<'info> {
     #[account(
         mut,
         seeds = [&b"produce"[..], &produce.produce_id.to_le_bytes()[..]],
-        bump
+        bump,
+        has_one = farmer @ ErrorCode::Unauthorized
     )]
     pub produce: Account<'info, Produce>,
     #[account(
@@ -215,76 +350,45 @@ pub struct RecordDelivery<'info> {
 pub fn confirm_delivery(
     accounts: &mut ConfirmDelivery,
     _bumps: &BTreeMap<String, u8>,
+    received_quantity: u64,
 ) -> Result<()> {
-    let retailer_account = &accounts.retailer_account;
-    if let crate::participant::ParticipantRole::Retailer = retailer_account.role {
-        // OK.
-    } else {
-        return Err(ErrorCode::Unauthorized.into());
-    }
     let produce = &mut accounts.produce;
+    // Only the buyer who funded the batch, whatever their role, may confirm
+    // what was received.
+    require!(accounts.retailer.key() == produce.buyer, ErrorCode::Unauthorized);
     require!(
         produce.status == ProduceStatus::InTransit,
         ErrorCode::InvalidStatus
     );
+    require!(received_quantity <= produce.quantity, ErrorCode::InvalidQuantity);
+
+    // Shrinkage within tolerance is borne by the farmer; losses beyond it are
+    // charged against the transporter fee.
+    let tolerance = (produce.quantity as u128
+        * accounts.payment_policy.shrinkage_tolerance_bps as u128
+        / 10_000) as u64;
+    let excess_loss = (produce.quantity - received_quantity).saturating_sub(tolerance);
+    produce.received_quantity = received_quantity;
+    produce.transit_loss_charge = produce.price_of(excess_loss);
 
-    // Update produce status
+    // Update produce status; inspections made in transit grade the batch now.
     produce.status = ProduceStatus::Delivered;
     produce.delivery_confirmed = true;
     produce.last_updated = Clock::get()?.unix_timestamp;
+    accounts.quality_config.grade(produce);
 
-    // Process payments
-    let farmer_reward = produce.farmer_price; // 1 lamport
-    let transporter_reward = produce.transporter_fee; // 1 lamport
-    let total_amount = farmer_reward + transporter_reward;
-
-    msg!("Vault balance before transfer: {}", accounts.payment_vault.amount);
-    msg!("Transferring to farmer: {} lamports", farmer_reward);
-    msg!("Transferring to transporter: {} lamports", transporter_reward);
+    let on_time = produce.last_updated - produce.picked_up_at <= ON_TIME_WINDOW;
+    accounts.farmer_reputation.record_delivery(on_time, produce.last_updated);
+    accounts.transporter_reputation.record_delivery(on_time, produce.last_updated);
 
-    // Check vault balance
-    if accounts.payment_vault.amount < total_amount {
-        msg!("Insufficient funds in vault: {} < {}", accounts.payment_vault.amount, total_amount);
-        return Err(ErrorCode::InsufficientFunds.into());
-    }
-
-    // Vault signer seeds with a let binding to extend lifetime
-    let vault_bump = accounts.vault.bump;
-    let bump_array = [vault_bump]; // Named binding to persist beyond this line
-    let vault_seeds = &[b"vault".as_ref(), &bump_array][..];
-    let signer = &[vault_seeds];
-
-    // Transfer to farmer
-    let cpi_accounts_farmer = Transfer {
-        from: accounts.payment_vault.to_account_info(),
-        to: accounts.farmer_payment_account.to_account_info(),
-        authority: accounts.vault.to_account_info(),
-    };
-    token::transfer(
-        CpiContext::new_with_signer(
-            accounts.token_program.to_account_info(),
-            cpi_accounts_farmer,
-            signer,
-        ),
-        farmer_reward,
-    )?;
-
-    // Transfer to transporter
-    let cpi_accounts_transporter = Transfer {
-        from: accounts.payment_vault.to_account_info(),
-        to: accounts.transporter_payment_account.to_account_info(),
-        authority: accounts.vault.to_account_info(),
-    };
-    token::transfer(
-        CpiContext::new_with_signer(
-            accounts.token_program.to_account_info(),
-            cpi_accounts_transporter,
-            signer,
-        ),
-        transporter_reward,
-    )?;
+    emit!(DeliveryReceived {
+        produce_id: produce.produce_id,
+        received_quantity,
+        transit_loss_charge: produce.transit_loss_charge,
+        timestamp: produce.last_updated,
+    });
 
-    msg!("Payments processed successfully");
+    // Payment is released per milestone; see release_milestone.
     Ok(())
 }
 
@@ -304,50 +408,12 @@ pub struct ConfirmDelivery<'info> {
     )]
     pub retailer_account: Account<'info, Participant>,
     pub retailer: Signer<'info>,
-    #[account(seeds = [b"vault"], bump)]
-    pub vault: Account<'info, crate::payment::Vault>,
-    #[account(mut, seeds = [&b"vault_token"[..]], bump)]
-    pub payment_vault: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub farmer_payment_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub transporter_payment_account: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
-}
-
-pub fn verify_quality(ctx: Context<VerifyQuality>, verified_quality: u8) -> Result<()> {
-    let verifier_account = &ctx.accounts.verifier_account;
-    match verifier_account.role {
-        crate::participant::ParticipantRole::Wholesaler
-        | crate::participant::ParticipantRole::Retailer => {}
-        _ => return Err(ErrorCode::Unauthorized.into()),
-    }
-    let produce = &mut ctx.accounts.produce;
-    produce.verified_quality = verified_quality;
-    produce.last_updated = Clock::get()?.unix_timestamp;
-    if verified_quality < 50 {
-        produce.status = ProduceStatus::Disputed;
-        produce.dispute_raised = true;
-    } else {
-        produce.status = ProduceStatus::QualityVerified;
-    }
-    Ok(())
+    #[account(mut, seeds = [b"reputation", produce.farmer.as_ref()], bump = farmer_reputation.bump)]
+    pub farmer_reputation: Account<'info, Reputation>,
+    #[account(mut, seeds = [b"reputation", produce.transporter.as_ref()], bump = transporter_reputation.bump)]
+    pub transporter_reputation: Account<'info, Reputation>,
+    #[account(seeds = [b"payment_policy"], bump = payment_policy.bump)]
+    pub payment_policy: Account<'info, PaymentPolicy>,
+    #[account(seeds = [b"quality_config"], bump = quality_config.bump)]
+    pub quality_config: Account<'info, QualityConfig>,
 }
-
-#[derive(Accounts)]
-pub struct VerifyQuality<'info> {
-    #[account(
-        mut,
-        seeds = [&b"produce"[..], &produce.produce_id.to_le_bytes()[..]],
-        bump
-    )]
-    pub produce: Account<'info, Produce>,
-    #[account(
-        mut,
-        seeds = [&b"participant"[..], verifier.key.as_ref()],
-        bump,
-        constraint = verifier_account.owner == verifier.key()
-    )]
-    pub verifier_account: Account<'info, Participant>,
-    pub verifier: Signer<'info>,
-}
\ No newline at end of file
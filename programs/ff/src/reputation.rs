@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use crate::participant::Participant;
+
+/// Transport temperatures (°C) outside this range count as cold-chain
+/// excursions.
+pub const COLD_CHAIN_MIN_TEMP: i16 = 0;
+pub const COLD_CHAIN_MAX_TEMP: i16 = 8;
+/// Deliveries confirmed within this many seconds of pickup count as on time.
+pub const ON_TIME_WINDOW: i64 = 3 * 24 * 60 * 60;
+
+/// Track record of a participant, maintained by the program as batches move
+/// through the supply chain. Counters saturate rather than fail so that a
+/// long history never blocks a shipment.
+#[account]
+pub struct Reputation {
+    pub owner: Pubkey,
+    pub deliveries_completed: u32,
+    pub on_time_deliveries: u32,
+    pub quality_reports: u32,         // harvests whose self-reported quality was verified
+    pub reported_quality_total: u64,  // sum of self-reported quality over those harvests
+    pub verified_quality_total: u64,  // sum of verified quality over those harvests
+    pub overstated_reports: u32,      // harvests reported above their verified quality
+    pub cold_chain_excursions: u32,
+    pub disputes_raised: u32,
+    pub disputes_lost: u32,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl Reputation {
+    // Size: owner (32) + deliveries_completed (4) + on_time_deliveries (4) + quality_reports (4)
+    // + reported_quality_total (8) + verified_quality_total (8) + overstated_reports (4)
+    // + cold_chain_excursions (4) + disputes_raised (4) + disputes_lost (4) + updated_at (8) + bump (1) = 85.
+    pub const LEN: usize = 85;
+
+    /// Share of completed deliveries that arrived on time, in basis points.
+    pub fn on_time_rate_bps(&self) -> u16 {
+        if self.deliveries_completed == 0 {
+            return 0;
+        }
+        (self.on_time_deliveries as u64 * 10_000 / self.deliveries_completed as u64) as u16
+    }
+
+    /// Average amount by which self-reported quality exceeded the verified
+    /// quality. Negative when the participant tends to under-report.
+    pub fn average_quality_gap(&self) -> i64 {
+        if self.quality_reports == 0 {
+            return 0;
+        }
+        (self.reported_quality_total as i64 - self.verified_quality_total as i64) / self.quality_reports as i64
+    }
+
+    pub fn record_delivery(&mut self, on_time: bool, now: i64) {
+        self.deliveries_completed = self.deliveries_completed.saturating_add(1);
+        if on_time {
+            self.on_time_deliveries = self.on_time_deliveries.saturating_add(1);
+        }
+        self.updated_at = now;
+    }
+
+    pub fn record_quality(&mut self, reported: u8, verified: u8, now: i64) {
+        self.quality_reports = self.quality_reports.saturating_add(1);
+        self.reported_quality_total = self.reported_quality_total.saturating_add(reported as u64);
+        self.verified_quality_total = self.verified_quality_total.saturating_add(verified as u64);
+        if reported > verified {
+            self.overstated_reports = self.overstated_reports.saturating_add(1);
+        }
+        self.updated_at = now;
+    }
+
+    /// Replaces the verified quality of a batch already recorded by
+    /// `record_quality` once further inspections move its effective score.
+    pub fn revise_quality(&mut self, reported: u8, previous: u8, verified: u8, now: i64) {
+        self.verified_quality_total = self
+            .verified_quality_total
+            .saturating_sub(previous as u64)
+            .saturating_add(verified as u64);
+        if reported > previous {
+            self.overstated_reports = self.overstated_reports.saturating_sub(1);
+        }
+        if reported > verified {
+            self.overstated_reports = self.overstated_reports.saturating_add(1);
+        }
+        self.updated_at = now;
+    }
+
+    /// Records a pickup temperature, counting it if it breaks the cold chain.
+    pub fn record_transport_temp(&mut self, temperature: i16, now: i64) {
+        if !(COLD_CHAIN_MIN_TEMP..=COLD_CHAIN_MAX_TEMP).contains(&temperature) {
+            self.cold_chain_excursions = self.cold_chain_excursions.saturating_add(1);
+            self.updated_at = now;
+        }
+    }
+
+    pub fn record_dispute_raised(&mut self, now: i64) {
+        self.disputes_raised = self.disputes_raised.saturating_add(1);
+        self.updated_at = now;
+    }
+
+    pub fn record_dispute_lost(&mut self, now: i64) {
+        self.disputes_lost = self.disputes_lost.saturating_add(1);
+        self.updated_at = now;
+    }
+}
+
+/// Creates the reputation account of a participant registered before
+/// reputations were tracked. Anyone may pay for it.
+pub fn initialize_reputation(ctx: Context<InitializeReputation>) -> Result<()> {
+    let reputation = &mut ctx.accounts.reputation;
+    reputation.owner = ctx.accounts.participant.owner;
+    reputation.updated_at = Clock::get()?.unix_timestamp;
+    reputation.bump = ctx.bumps.reputation;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeReputation<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Reputation::LEN,
+        seeds = [b"reputation", participant.owner.as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+    #[account(seeds = [b"participant", participant.owner.as_ref()], bump)]
+    pub participant: Account<'info, Participant>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
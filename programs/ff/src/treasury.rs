@@ -0,0 +1,300 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::error::ErrorCode;
+use crate::governance::{GovernanceConfig, GovernanceProposal, ProposalAction};
+use crate::payment::{MintAllowlist, NATIVE_SOL};
+
+/// Upper bound on each protocol fee.
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 1_000;
+
+/// Protocol treasury. Holds fees in a token account per accepted mint, and
+/// fees on native SOL batches as lamports on this account. Funds leave it
+/// only through executed `TreasurySpend` proposals.
+#[account]
+pub struct Treasury {
+    pub settlement_fee_bps: u16, // charged to the buyer on top of each payout
+    pub bond_fee_bps: u16,       // charged to whoever posts a dispute or appeal bond
+    pub bump: u8,
+}
+
+impl Treasury {
+    // Size: settlement_fee_bps (2) + bond_fee_bps (2) + bump (1) = 5.
+    pub const LEN: usize = 5;
+}
+
+#[event]
+pub struct ProtocolFeeCollected {
+    pub produce: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasurySpent {
+    pub proposal_id: u64,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Fee of `bps` basis points on `amount`.
+pub fn protocol_fee(amount: u64, bps: u16) -> u64 {
+    (amount as u128 * bps as u128 / 10_000) as u64
+}
+
+fn validate_fees(settlement_fee_bps: u16, bond_fee_bps: u16) -> Result<()> {
+    require!(
+        settlement_fee_bps <= MAX_PROTOCOL_FEE_BPS && bond_fee_bps <= MAX_PROTOCOL_FEE_BPS,
+        ErrorCode::InvalidConfig
+    );
+    Ok(())
+}
+
+/// Creates the treasury. Governance admin only.
+pub fn initialize_treasury(
+    ctx: Context<InitializeTreasury>,
+    settlement_fee_bps: u16,
+    bond_fee_bps: u16,
+) -> Result<()> {
+    validate_fees(settlement_fee_bps, bond_fee_bps)?;
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.settlement_fee_bps = settlement_fee_bps;
+    treasury.bond_fee_bps = bond_fee_bps;
+    treasury.bump = ctx.bumps.treasury;
+    Ok(())
+}
+
+/// Updates the protocol fees. Governance admin only; batches keep the
+/// settlement fee in force when they were funded.
+pub fn update_treasury_fees(
+    ctx: Context<UpdateTreasuryFees>,
+    settlement_fee_bps: u16,
+    bond_fee_bps: u16,
+) -> Result<()> {
+    validate_fees(settlement_fee_bps, bond_fee_bps)?;
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.settlement_fee_bps = settlement_fee_bps;
+    treasury.bond_fee_bps = bond_fee_bps;
+    Ok(())
+}
+
+/// Creates the treasury's token account for an accepted mint. Anyone may pay
+/// for it.
+pub fn open_treasury_account(ctx: Context<OpenTreasuryAccount>) -> Result<()> {
+    require!(
+        ctx.accounts.mint_allowlist.is_allowed(&ctx.accounts.mint.key()),
+        ErrorCode::MintNotAllowed
+    );
+    Ok(())
+}
+
+/// Transfers a protocol fee from a payer's token account into the treasury.
+pub fn collect_fee<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    authority: &Signer<'info>,
+    treasury_vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    produce: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let cpi_accounts = TransferChecked {
+        from: from.to_account_info(),
+        mint: mint.to_account_info(),
+        to: treasury_vault.to_account_info(),
+        authority: authority.to_account_info(),
+    };
+    token_interface::transfer_checked(
+        CpiContext::new(token_program.to_account_info(), cpi_accounts),
+        amount,
+        mint.decimals,
+    )?;
+    emit!(ProtocolFeeCollected {
+        produce,
+        mint: mint.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Transfers a protocol fee in lamports from a payer into the treasury.
+pub fn collect_sol_fee<'info>(
+    system_program: &Program<'info, System>,
+    from: &Signer<'info>,
+    treasury: &Account<'info, Treasury>,
+    produce: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let cpi_accounts = system_program::Transfer {
+        from: from.to_account_info(),
+        to: treasury.to_account_info(),
+    };
+    system_program::transfer(CpiContext::new(system_program.to_account_info(), cpi_accounts), amount)?;
+    emit!(ProtocolFeeCollected {
+        produce,
+        mint: NATIVE_SOL,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Pays out a `TreasurySpend` proposal from the treasury's token account.
+pub fn execute_treasury_proposal(ctx: Context<ExecuteTreasuryProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let ProposalAction::TreasurySpend { mint, recipient, amount } = proposal.action.clone() else {
+        return Err(ErrorCode::ProposalActionMismatch.into());
+    };
+    require!(mint == ctx.accounts.mint.key(), ErrorCode::MintMismatch);
+    require!(
+        recipient == ctx.accounts.recipient_token_account.owner,
+        ErrorCode::InvalidPayoutAccount
+    );
+    proposal.begin_execution()?;
+
+    let bump_array = [ctx.accounts.treasury.bump];
+    let signer: &[&[&[u8]]] = &[&[b"treasury".as_ref(), &bump_array]];
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.treasury_vault.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.treasury.to_account_info(),
+    };
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(TreasurySpent {
+        proposal_id: proposal.proposal_id,
+        mint,
+        recipient,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Pays out a `TreasurySpend` proposal in native SOL from the treasury's
+/// lamports above its rent-exempt minimum.
+pub fn execute_treasury_sol_proposal(ctx: Context<ExecuteTreasurySolProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let ProposalAction::TreasurySpend { mint, recipient, amount } = proposal.action.clone() else {
+        return Err(ErrorCode::ProposalActionMismatch.into());
+    };
+    require!(mint == NATIVE_SOL, ErrorCode::MintMismatch);
+    require!(recipient == ctx.accounts.recipient.key(), ErrorCode::InvalidPayoutAccount);
+    proposal.begin_execution()?;
+
+    let treasury = ctx.accounts.treasury.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(treasury.data_len());
+    require!(
+        treasury.lamports().saturating_sub(rent_exempt) >= amount,
+        ErrorCode::InsufficientFunds
+    );
+    treasury.sub_lamports(amount)?;
+    ctx.accounts.recipient.add_lamports(amount)?;
+
+    emit!(TreasurySpent {
+        proposal_id: proposal.proposal_id,
+        mint,
+        recipient,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Treasury::LEN,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTreasuryFees<'info> {
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GovernanceConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenTreasuryAccount<'info> {
+    #[account(seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = treasury,
+        token::token_program = token_program,
+        seeds = [b"treasury_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"mint_allowlist"], bump = mint_allowlist.bump)]
+    pub mint_allowlist: Account<'info, MintAllowlist>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteTreasuryProposal<'info> {
+    #[account(mut, seeds = [b"proposal", &proposal.proposal_id.to_le_bytes()], bump)]
+    pub proposal: Account<'info, GovernanceProposal>,
+    #[account(seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut, seeds = [b"treasury_vault", mint.key().as_ref()], bump)]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub executor: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteTreasurySolProposal<'info> {
+    #[account(mut, seeds = [b"proposal", &proposal.proposal_id.to_le_bytes()], bump)]
+    pub proposal: Account<'info, GovernanceProposal>,
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+    pub executor: Signer<'info>,
+}
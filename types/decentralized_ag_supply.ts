@@ -14,41 +14,39 @@ export type DecentralizedAgSupply = {
   },
   "instructions": [
     {
-      "name": "confirmDelivery",
+      "name": "allowMint",
       "discriminator": [
-        11,
-        109,
-        227,
-        53,
-        179,
-        190,
-        88,
-        155
+        240,
+        28,
+        240,
+        70,
+        124,
+        240,
+        245,
+        225
       ],
       "accounts": [
         {
-          "name": "produce",
-          "writable": true
-        },
-        {
-          "name": "retailerAccount",
-          "writable": true
-        },
-        {
-          "name": "retailer",
-          "signer": true
-        },
-        {
-          "name": "vault",
+          "name": "mintAllowlist",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
                   97,
-                  117,
                   108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
                   116
                 ]
               }
@@ -56,450 +54,425 @@ export type DecentralizedAgSupply = {
           }
         },
         {
-          "name": "paymentVault",
-          "writable": true
-        },
-        {
-          "name": "farmerPaymentAccount",
-          "writable": true
-        },
-        {
-          "name": "transporterPaymentAccount",
-          "writable": true
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "mint",
+          "type": "pubkey"
+        }
+      ]
     },
     {
-      "name": "confirmPickup",
+      "name": "answerDispute",
       "discriminator": [
-        37,
-        5,
-        149,
-        215,
-        41,
-        79,
-        248,
-        82
+        120,
+        22,
+        21,
+        195,
+        60,
+        57,
+        40,
+        204
       ],
       "accounts": [
         {
-          "name": "produce",
+          "name": "dispute",
           "writable": true
         },
         {
-          "name": "farmerAccount",
-          "writable": true
+          "name": "produce",
+          "relations": [
+            "dispute"
+          ]
         },
         {
-          "name": "farmer",
+          "name": "respondent",
+          "writable": true,
           "signer": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "createProposal",
-      "discriminator": [
-        132,
-        116,
-        68,
-        174,
-        216,
-        160,
-        198,
-        22
-      ],
-      "accounts": [
+        },
         {
-          "name": "proposal",
+          "name": "respondentTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "escrow",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  101,
+                  115,
+                  99,
                   114,
                   111,
-                  112,
-                  111,
-                  115,
-                  97,
-                  108
+                  119
                 ]
               },
               {
-                "kind": "arg",
-                "path": "proposalId"
+                "kind": "account",
+                "path": "produce"
+              },
+              {
+                "kind": "account",
+                "path": "produce.settlement_mint",
+                "account": "produce"
               }
             ]
           }
         },
         {
-          "name": "proposer",
-          "writable": true,
-          "signer": true
+          "name": "mint"
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "proposalId",
-          "type": "u64"
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "description",
-          "type": "string"
-        }
-      ]
-    },
-    {
-      "name": "executeProposal",
-      "discriminator": [
-        186,
-        60,
-        116,
-        133,
-        108,
-        128,
-        111,
-        28
-      ],
-      "accounts": [
-        {
-          "name": "proposal",
+          "name": "treasuryVault",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  116,
                   114,
-                  111,
-                  112,
-                  111,
+                  101,
+                  97,
                   115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
                   97,
-                  108
+                  117,
+                  108,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "proposal.proposal_id",
-                "account": "governanceProposal"
+                "path": "produce.settlement_mint",
+                "account": "produce"
               }
             ]
           }
         },
         {
-          "name": "executor",
-          "writable": true,
-          "signer": true
+          "name": "tokenProgram"
         }
       ],
-      "args": [
-        {
-          "name": "proposalId",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "fundVault",
+      "name": "answerDisputeSol",
       "discriminator": [
-        26,
+        225,
         33,
-        207,
-        242,
-        119,
-        108,
-        134,
-        73
+        7,
+        195,
+        211,
+        184,
+        139,
+        148
       ],
       "accounts": [
         {
-          "name": "produce",
+          "name": "dispute",
           "writable": true
         },
         {
-          "name": "retailer",
-          "writable": true,
-          "signer": true
+          "name": "produce",
+          "relations": [
+            "dispute"
+          ]
         },
         {
-          "name": "retailerTokenAccount",
-          "writable": true
+          "name": "respondent",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "paymentVault",
+          "name": "solEscrow",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  97,
-                  117,
+                  115,
+                  111,
                   108,
-                  116,
                   95,
-                  116,
-                  111,
-                  107,
                   101,
-                  110
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
               }
             ]
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "initializeVault",
-      "discriminator": [
-        48,
-        191,
-        163,
-        44,
-        71,
-        129,
-        63,
-        164
-      ],
-      "accounts": [
-        {
-          "name": "vault",
+          "name": "treasury",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
+                  116,
+                  114,
+                  101,
                   97,
+                  115,
                   117,
-                  108,
-                  116
+                  114,
+                  121
                 ]
               }
             ]
           }
         },
         {
-          "name": "paymentVault",
-          "writable": true,
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "appealDispute",
+      "discriminator": [
+        245,
+        174,
+        189,
+        158,
+        146,
+        77,
+        39,
+        32
+      ],
+      "accounts": [
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "produce",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "disputeConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
-                  97,
+                  100,
+                  105,
+                  115,
+                  112,
                   117,
-                  108,
                   116,
+                  101,
                   95,
-                  116,
+                  99,
                   111,
-                  107,
-                  101,
-                  110
+                  110,
+                  102,
+                  105,
+                  103
                 ]
               }
             ]
           }
         },
         {
-          "name": "mint"
-        },
-        {
-          "name": "authority",
+          "name": "appellant",
           "writable": true,
           "signer": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "logHarvest",
-      "discriminator": [
-        35,
-        162,
-        14,
-        136,
-        29,
-        210,
-        195,
-        26
-      ],
-      "accounts": [
-        {
-          "name": "produce",
-          "writable": true
-        },
-        {
-          "name": "farmerAccount",
+          "name": "appellantTokenAccount",
           "writable": true
         },
         {
-          "name": "farmer",
+          "name": "escrow",
           "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "produceId",
-          "type": "u64"
-        },
-        {
-          "name": "produceType",
-          "type": "string"
-        },
-        {
-          "name": "quantity",
-          "type": "u64"
-        },
-        {
-          "name": "harvestDate",
-          "type": "i64"
-        },
-        {
-          "name": "quality",
-          "type": "u8"
-        },
-        {
-          "name": "qrCodeUri",
-          "type": "string"
-        },
-        {
-          "name": "farmerPrice",
-          "type": "u64"
-        },
-        {
-          "name": "transporterFee",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "processPayment",
-      "discriminator": [
-        189,
-        81,
-        30,
-        198,
-        139,
-        186,
-        115,
-        23
-      ],
-      "accounts": [
-        {
-          "name": "produce",
-          "writable": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              },
+              {
+                "kind": "account",
+                "path": "produce.settlement_mint",
+                "account": "produce"
+              }
+            ]
+          }
         },
         {
-          "name": "farmerPaymentAccount",
-          "writable": true
+          "name": "mint"
         },
         {
-          "name": "transporterPaymentAccount",
-          "writable": true
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "paymentVault",
+          "name": "treasuryVault",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
                   118,
                   97,
                   117,
                   108,
-                  116,
-                  95,
-                  116,
-                  111,
-                  107,
-                  101,
-                  110
+                  116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "produce.settlement_mint",
+                "account": "produce"
               }
             ]
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "tokenProgram"
         }
       ],
       "args": []
     },
     {
-      "name": "raiseDispute",
+      "name": "appealDisputeSol",
       "discriminator": [
-        41,
-        243,
-        1,
-        51,
-        150,
+        111,
+        200,
+        13,
+        188,
+        239,
+        142,
         95,
-        246,
-        73
+        67
       ],
       "accounts": [
         {
-          "name": "produce",
+          "name": "dispute",
           "writable": true
         },
         {
-          "name": "raiser",
-          "writable": true,
-          "signer": true
+          "name": "produce",
+          "relations": [
+            "dispute"
+          ]
         },
         {
-          "name": "dispute",
-          "writable": true,
+          "name": "disputeConfig",
           "pda": {
             "seeds": [
               {
@@ -511,384 +484,408 @@ export type DecentralizedAgSupply = {
                   112,
                   117,
                   116,
-                  101
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "produce"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
+          "name": "appellant",
+          "writable": true,
+          "signer": true
+        },
         {
-          "name": "description",
-          "type": "string"
-        }
-      ]
-    },
-    {
-      "name": "recordDelivery",
-      "discriminator": [
-        72,
-        245,
-        251,
-        211,
-        129,
-        177,
-        93,
-        214
-      ],
-      "accounts": [
-        {
-          "name": "produce",
-          "writable": true
-        },
-        {
-          "name": "transporterAccount",
-          "writable": true
-        },
-        {
-          "name": "transporter",
-          "signer": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "recordPickup",
-      "discriminator": [
-        133,
-        238,
-        8,
-        81,
-        210,
-        250,
-        186,
-        73
-      ],
-      "accounts": [
-        {
-          "name": "produce",
-          "writable": true
-        },
-        {
-          "name": "transporterAccount",
-          "writable": true
-        },
-        {
-          "name": "transporter",
-          "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "temperature",
-          "type": "i16"
-        },
-        {
-          "name": "humidity",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "registerParticipant",
-      "discriminator": [
-        248,
-        112,
-        38,
-        215,
-        226,
-        230,
-        249,
-        40
-      ],
-      "accounts": [
-        {
-          "name": "participant",
+          "name": "solEscrow",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  97,
-                  114,
-                  116,
-                  105,
+                  115,
+                  111,
+                  108,
+                  95,
+                  101,
+                  115,
                   99,
-                  105,
-                  112,
-                  97,
-                  110,
-                  116
+                  114,
+                  111,
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "user"
+                "path": "produce"
               }
             ]
           }
         },
         {
-          "name": "user",
+          "name": "treasury",
           "writable": true,
-          "signer": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
         },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "role",
-          "type": {
-            "defined": {
-              "name": "participantRole"
-            }
-          }
-        },
-        {
-          "name": "name",
-          "type": "string"
-        },
-        {
-          "name": "contactInfo",
-          "type": "string"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "resolveDispute",
+      "name": "appointArbitrator",
       "discriminator": [
-        231,
-        6,
-        202,
-        6,
-        96,
-        103,
-        12,
-        230
+        181,
+        135,
+        10,
+        105,
+        127,
+        46,
+        48,
+        226
       ],
       "accounts": [
         {
-          "name": "dispute",
-          "writable": true
-        },
-        {
-          "name": "produce",
-          "writable": true
-        },
-        {
-          "name": "arbitratorAccount",
+          "name": "registry",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
                   97,
                   114,
-                  116,
+                  98,
                   105,
-                  99,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
                   105,
-                  112,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
                   97,
                   110,
-                  116
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "arbitrator"
               }
             ]
           }
         },
         {
-          "name": "arbitrator",
-          "signer": true
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         }
       ],
       "args": [
         {
-          "name": "resolution",
-          "type": "bool"
+          "name": "arbitrator",
+          "type": "pubkey"
         }
       ]
     },
     {
-      "name": "stakeTokens",
+      "name": "assignPanel",
       "discriminator": [
-        136,
-        126,
-        91,
-        162,
-        40,
-        131,
-        13,
-        127
+        187,
+        223,
+        120,
+        186,
+        139,
+        197,
+        113,
+        240
       ],
       "accounts": [
         {
-          "name": "staker",
-          "writable": true,
-          "signer": true
+          "name": "dispute",
+          "writable": true
         },
         {
-          "name": "stakerTokenAccount",
-          "writable": true
+          "name": "produce",
+          "relations": [
+            "dispute"
+          ]
         },
         {
-          "name": "stakeVault",
-          "writable": true,
+          "name": "disputeConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  100,
+                  105,
                   115,
+                  112,
+                  117,
                   116,
-                  97,
-                  107,
                   101,
                   95,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
               }
             ]
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "unstakeTokens",
-      "discriminator": [
-        58,
-        119,
-        215,
-        143,
-        203,
-        223,
-        32,
-        86
-      ],
-      "accounts": [
-        {
-          "name": "staker",
-          "writable": true,
-          "signer": true
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  114,
+                  98,
+                  105,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "stakerTokenAccount",
-          "writable": true
-        },
+          "name": "slotHashes",
+          "address": "SysvarS1otHashes111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelProposal",
+      "discriminator": [
+        106,
+        74,
+        128,
+        146,
+        19,
+        65,
+        39,
+        23
+      ],
+      "accounts": [
         {
-          "name": "stakeVault",
+          "name": "proposal",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
                   115,
-                  116,
-                  97,
-                  107,
-                  101,
-                  95,
-                  118,
                   97,
-                  117,
-                  108,
-                  116
+                  108
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "governanceProposal"
               }
             ]
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "proposer",
+          "signer": true,
+          "relations": [
+            "proposal"
+          ]
         }
       ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "verifyQuality",
+      "name": "certifyInspector",
       "discriminator": [
-        53,
-        102,
-        132,
-        199,
-        27,
-        42,
-        97,
-        41
+        94,
+        164,
+        34,
+        13,
+        65,
+        152,
+        56,
+        224
       ],
       "accounts": [
         {
-          "name": "produce",
-          "writable": true
+          "name": "certification",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  112,
+                  101,
+                  99,
+                  116,
+                  111,
+                  114,
+                  95,
+                  99,
+                  101,
+                  114,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "inspector"
+              }
+            ]
+          }
         },
         {
-          "name": "verifierAccount",
-          "writable": true
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "verifier",
-          "signer": true
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "verifiedQuality",
-          "type": "u8"
+          "name": "inspector",
+          "type": "pubkey"
         }
       ]
     },
     {
-      "name": "voteProposal",
+      "name": "changeVote",
       "discriminator": [
-        247,
-        104,
-        114,
-        240,
-        237,
-        41,
-        200,
-        36
+        184,
+        39,
+        97,
+        137,
+        83,
+        108,
+        185,
+        75
       ],
       "accounts": [
         {
@@ -918,146 +915,9915 @@ export type DecentralizedAgSupply = {
           }
         },
         {
-          "name": "voter",
+          "name": "voteRecord",
           "writable": true,
-          "signer": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal"
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voter",
+          "signer": true,
+          "relations": [
+            "voteRecord"
+          ]
         }
       ],
       "args": [
         {
-          "name": "proposalId",
-          "type": "u64"
-        },
-        {
-          "name": "voteFor",
-          "type": "bool"
+          "name": "choice",
+          "type": {
+            "defined": {
+              "name": "voteChoice"
+            }
+          }
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "dispute",
-      "discriminator": [
-        36,
-        49,
-        241,
-        67,
-        40,
-        36,
-        241,
-        74
-      ]
-    },
-    {
-      "name": "governanceProposal",
-      "discriminator": [
-        53,
-        107,
-        240,
-        190,
-        43,
-        73,
-        65,
-        143
-      ]
-    },
-    {
-      "name": "participant",
-      "discriminator": [
-        32,
-        142,
-        108,
-        79,
-        247,
-        179,
-        54,
-        6
-      ]
-    },
-    {
-      "name": "produce",
-      "discriminator": [
-        202,
-        115,
-        118,
-        182,
-        195,
-        125,
-        51,
-        61
-      ]
     },
     {
-      "name": "vault",
+      "name": "claimArbitrationFees",
+      "discriminator": [
+        234,
+        210,
+        30,
+        158,
+        138,
+        215,
+        154,
+        122
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  114,
+                  98,
+                  105,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "produce",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "arbitrator",
+          "signer": true
+        },
+        {
+          "name": "arbitratorTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              },
+              {
+                "kind": "account",
+                "path": "produce.settlement_mint",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimArbitrationFeesSol",
+      "discriminator": [
+        136,
+        210,
+        237,
+        189,
+        162,
+        185,
+        56,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  114,
+                  98,
+                  105,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "produce",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "arbitrator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "solEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimInspectionFees",
+      "discriminator": [
+        52,
+        199,
+        107,
+        18,
+        100,
+        35,
+        193,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "certification",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  112,
+                  101,
+                  99,
+                  116,
+                  111,
+                  114,
+                  95,
+                  99,
+                  101,
+                  114,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "inspector"
+              }
+            ]
+          }
+        },
+        {
+          "name": "inspection",
+          "writable": true
+        },
+        {
+          "name": "produce",
+          "relations": [
+            "inspection"
+          ]
+        },
+        {
+          "name": "inspector",
+          "signer": true,
+          "relations": [
+            "inspection"
+          ]
+        },
+        {
+          "name": "inspectorTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              },
+              {
+                "kind": "account",
+                "path": "produce.settlement_mint",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimInspectionFeesSol",
+      "discriminator": [
+        84,
+        250,
+        179,
+        151,
+        85,
+        235,
+        229,
+        124
+      ],
+      "accounts": [
+        {
+          "name": "certification",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  112,
+                  101,
+                  99,
+                  116,
+                  111,
+                  114,
+                  95,
+                  99,
+                  101,
+                  114,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "inspector"
+              }
+            ]
+          }
+        },
+        {
+          "name": "inspection",
+          "writable": true
+        },
+        {
+          "name": "produce",
+          "relations": [
+            "inspection"
+          ]
+        },
+        {
+          "name": "inspector",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "inspection"
+          ]
+        },
+        {
+          "name": "solEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimInsurance",
+      "discriminator": [
+        96,
+        254,
+        157,
+        145,
+        19,
+        96,
+        95,
+        55
+      ],
+      "accounts": [
+        {
+          "name": "produce"
+        },
+        {
+          "name": "policy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  112,
+                  111,
+                  108,
+                  105,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dispute",
+          "docs": [
+            "Required for spoilage claims."
+          ],
+          "optional": true
+        },
+        {
+          "name": "insurancePool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "insuranceVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "insured",
+          "signer": true,
+          "relations": [
+            "policy"
+          ]
+        },
+        {
+          "name": "insuredTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "claimReason"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "confirmDelivery",
+      "discriminator": [
+        11,
+        109,
+        227,
+        53,
+        179,
+        190,
+        88,
+        155
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "retailerAccount",
+          "writable": true
+        },
+        {
+          "name": "retailer",
+          "signer": true
+        },
+        {
+          "name": "farmerReputation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  117,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce.farmer",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "transporterReputation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  117,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce.transporter",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "paymentPolicy",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  112,
+                  111,
+                  108,
+                  105,
+                  99,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "qualityConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  113,
+                  117,
+                  97,
+                  108,
+                  105,
+                  116,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "receivedQuantity",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "confirmPickup",
+      "discriminator": [
+        37,
+        5,
+        149,
+        215,
+        41,
+        79,
+        248,
+        82
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "farmerAccount",
+          "writable": true
+        },
+        {
+          "name": "farmer",
+          "signer": true,
+          "relations": [
+            "produce"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createProposal",
+      "discriminator": [
+        132,
+        116,
+        68,
+        174,
+        216,
+        160,
+        198,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.proposal_count",
+                "account": "governanceConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposerStake",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalType",
+          "type": {
+            "defined": {
+              "name": "proposalType"
+            }
+          }
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": {
+              "name": "proposalAction"
+            }
+          }
+        },
+        {
+          "name": "description",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "delegateVotes",
+      "discriminator": [
+        134,
+        214,
+        180,
+        254,
+        134,
+        143,
+        185,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "delegatorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegateAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegate_account.owner",
+                "account": "participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegator",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "disallowMint",
+      "discriminator": [
+        75,
+        176,
+        193,
+        187,
+        34,
+        158,
+        118,
+        121
+      ],
+      "accounts": [
+        {
+          "name": "mintAllowlist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "executeArbitratorProposal",
+      "discriminator": [
+        208,
+        184,
+        14,
+        113,
+        150,
+        102,
+        84,
+        17
+      ],
+      "accounts": [
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "governanceProposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  114,
+                  98,
+                  105,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "executor",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeInspectorProposal",
+      "discriminator": [
+        139,
+        117,
+        65,
+        237,
+        108,
+        176,
+        66,
+        52
+      ],
+      "accounts": [
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "governanceProposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "certification",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  112,
+                  101,
+                  99,
+                  116,
+                  111,
+                  114,
+                  95,
+                  99,
+                  101,
+                  114,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "inspector"
+              }
+            ]
+          }
+        },
+        {
+          "name": "executor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "inspector",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "executeMintProposal",
+      "discriminator": [
+        254,
+        213,
+        7,
+        161,
+        254,
+        151,
+        99,
+        63
+      ],
+      "accounts": [
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "governanceProposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintAllowlist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "executor",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeProposal",
+      "discriminator": [
+        186,
+        60,
+        116,
+        133,
+        108,
+        128,
+        111,
+        28
+      ],
+      "accounts": [
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "governanceProposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "executor",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "executeTreasuryProposal",
+      "discriminator": [
+        5,
+        227,
+        38,
+        189,
+        227,
+        34,
+        99,
+        106
+      ],
+      "accounts": [
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "governanceProposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipientTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "executor",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeTreasurySolProposal",
+      "discriminator": [
+        193,
+        181,
+        146,
+        216,
+        49,
+        72,
+        150,
+        105
+      ],
+      "accounts": [
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "governanceProposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipient",
+          "writable": true
+        },
+        {
+          "name": "executor",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeDispute",
+      "discriminator": [
+        190,
+        211,
+        17,
+        122,
+        247,
+        157,
+        27,
+        223
+      ],
+      "accounts": [
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "produce",
+          "writable": true,
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  114,
+                  98,
+                  105,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "loserReputation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  117,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "loser_reputation.owner",
+                "account": "reputation"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              },
+              {
+                "kind": "account",
+                "path": "produce.settlement_mint",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "farmerPaymentAccount",
+          "writable": true
+        },
+        {
+          "name": "transporterPaymentAccount",
+          "writable": true
+        },
+        {
+          "name": "buyerPaymentAccount",
+          "writable": true
+        },
+        {
+          "name": "bondRecipientAccount",
+          "docs": [
+            "Receives the dispute bond: the raiser if upheld, otherwise the counterparty."
+          ],
+          "writable": true
+        },
+        {
+          "name": "appealBondRecipientAccount",
+          "docs": [
+            "Receives the appeal bond, if any: the appellant if the appeal succeeded."
+          ],
+          "writable": true
+        },
+        {
+          "name": "responseBondRecipientAccount",
+          "docs": [
+            "Receives the response bond, if any: the raiser if upheld, otherwise the respondent."
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "treasuryVault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce.settlement_mint",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "invoicing",
+          "accounts": [
+            {
+              "name": "produce"
+            },
+            {
+              "name": "invoice",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      105,
+                      110,
+                      118,
+                      111,
+                      105,
+                      99,
+                      101
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "produce"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "invoiceCounter",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      105,
+                      110,
+                      118,
+                      111,
+                      105,
+                      99,
+                      101,
+                      95,
+                      99,
+                      111,
+                      117,
+                      110,
+                      116,
+                      101,
+                      114
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeDisputeSol",
+      "discriminator": [
+        102,
+        181,
+        106,
+        97,
+        101,
+        91,
+        187,
+        52
+      ],
+      "accounts": [
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "produce",
+          "writable": true,
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  114,
+                  98,
+                  105,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "loserReputation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  117,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "loser_reputation.owner",
+                "account": "reputation"
+              }
+            ]
+          }
+        },
+        {
+          "name": "solEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "farmer",
+          "writable": true
+        },
+        {
+          "name": "transporter",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "bondRecipient",
+          "docs": [
+            "Receives the dispute bond: the raiser if upheld, otherwise the counterparty."
+          ],
+          "writable": true
+        },
+        {
+          "name": "appealBondRecipient",
+          "docs": [
+            "Receives the appeal bond, if any: the appellant if the appeal succeeded."
+          ],
+          "writable": true
+        },
+        {
+          "name": "responseBondRecipient",
+          "docs": [
+            "Receives the response bond, if any: the raiser if upheld, otherwise the respondent."
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "invoicing",
+          "accounts": [
+            {
+              "name": "produce"
+            },
+            {
+              "name": "invoice",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      105,
+                      110,
+                      118,
+                      111,
+                      105,
+                      99,
+                      101
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "produce"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "invoiceCounter",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      105,
+                      110,
+                      118,
+                      111,
+                      105,
+                      99,
+                      101,
+                      95,
+                      99,
+                      111,
+                      117,
+                      110,
+                      116,
+                      101,
+                      114
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeProposal",
+      "discriminator": [
+        23,
+        68,
+        51,
+        167,
+        109,
+        173,
+        187,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "governanceProposal"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fundVault",
+      "discriminator": [
+        26,
+        33,
+        207,
+        242,
+        119,
+        108,
+        134,
+        73
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "retailer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "retailerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "mintAllowlist",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "qualityConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  113,
+                  117,
+                  97,
+                  108,
+                  105,
+                  116,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Holds this batch's funds in its settlement mint."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "fundVaultSol",
+      "discriminator": [
+        212,
+        116,
+        174,
+        191,
+        17,
+        145,
+        208,
+        184
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "retailer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mintAllowlist",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "qualityConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  113,
+                  117,
+                  97,
+                  108,
+                  105,
+                  116,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "solEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeArbitratorRegistry",
+      "discriminator": [
+        128,
+        108,
+        139,
+        155,
+        83,
+        120,
+        189,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  114,
+                  98,
+                  105,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeDisputeConfig",
+      "discriminator": [
+        162,
+        84,
+        147,
+        254,
+        220,
+        60,
+        174,
+        65
+      ],
+      "accounts": [
+        {
+          "name": "disputeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "disputeParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeGovernance",
+      "discriminator": [
+        171,
+        87,
+        101,
+        237,
+        27,
+        107,
+        201,
+        57
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "votingPeriod",
+          "type": "i64"
+        },
+        {
+          "name": "quorum",
+          "type": "u64"
+        },
+        {
+          "name": "minProposerStake",
+          "type": "u64"
+        },
+        {
+          "name": "timelock",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "initializeInsurancePool",
+      "discriminator": [
+        109,
+        87,
+        201,
+        131,
+        246,
+        236,
+        11,
+        238
+      ],
+      "accounts": [
+        {
+          "name": "insurancePool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Holds premiums and underwriting capital."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakeVault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "premiumBps",
+          "type": "u16"
+        },
+        {
+          "name": "coverageBps",
+          "type": {
+            "array": [
+              "u16",
+              2
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeMintAllowlist",
+      "discriminator": [
+        132,
+        223,
+        5,
+        70,
+        96,
+        239,
+        66,
+        43
+      ],
+      "accounts": [
+        {
+          "name": "mintAllowlist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializePaymentPolicy",
+      "discriminator": [
+        186,
+        97,
+        182,
+        164,
+        25,
+        109,
+        101,
+        8
+      ],
+      "accounts": [
+        {
+          "name": "paymentPolicy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  112,
+                  111,
+                  108,
+                  105,
+                  99,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "shrinkageToleranceBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "initializeQualityConfig",
+      "discriminator": [
+        172,
+        174,
+        0,
+        166,
+        234,
+        227,
+        213,
+        201
+      ],
+      "accounts": [
+        {
+          "name": "qualityConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  113,
+                  117,
+                  97,
+                  108,
+                  105,
+                  116,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "certifiedThreshold",
+          "type": "u64"
+        },
+        {
+          "name": "minQuality",
+          "type": "u8"
+        },
+        {
+          "name": "inspectionFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeReputation",
+      "discriminator": [
+        150,
+        240,
+        109,
+        53,
+        147,
+        42,
+        152,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "reputation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  117,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "participant.owner",
+                "account": "participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "participant",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "participant.owner",
+                "account": "participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeTreasury",
+      "discriminator": [
+        124,
+        186,
+        211,
+        195,
+        85,
+        165,
+        129,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "settlementFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "bondFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "initializeVault",
+      "discriminator": [
+        48,
+        191,
+        163,
+        44,
+        71,
+        129,
+        63,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "insureBatch",
+      "discriminator": [
+        152,
+        177,
+        185,
+        98,
+        3,
+        111,
+        180,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "produce"
+        },
+        {
+          "name": "policy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  112,
+                  111,
+                  108,
+                  105,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "insurancePool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "insuranceVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "insured",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "insuredTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "logHarvest",
+      "discriminator": [
+        35,
+        162,
+        14,
+        136,
+        29,
+        210,
+        195,
+        26
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "farmerAccount",
+          "writable": true
+        },
+        {
+          "name": "farmer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "produceId",
+          "type": "u64"
+        },
+        {
+          "name": "produceType",
+          "type": "string"
+        },
+        {
+          "name": "quantity",
+          "type": "u64"
+        },
+        {
+          "name": "harvestDate",
+          "type": "i64"
+        },
+        {
+          "name": "quality",
+          "type": "u8"
+        },
+        {
+          "name": "qrCodeUri",
+          "type": "string"
+        },
+        {
+          "name": "farmerPrice",
+          "type": "u64"
+        },
+        {
+          "name": "transporterFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "openTreasuryAccount",
+      "discriminator": [
+        17,
+        36,
+        92,
+        122,
+        183,
+        114,
+        147,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "mintAllowlist",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "processPayment",
+      "discriminator": [
+        189,
+        81,
+        30,
+        198,
+        139,
+        186,
+        115,
+        23
+      ],
+      "accounts": [
+        {
+          "name": "payment",
+          "accounts": [
+            {
+              "name": "produce",
+              "writable": true
+            },
+            {
+              "name": "farmerPaymentAccount",
+              "writable": true
+            },
+            {
+              "name": "transporterPaymentAccount",
+              "writable": true
+            },
+            {
+              "name": "buyerPaymentAccount",
+              "writable": true
+            },
+            {
+              "name": "vault",
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      118,
+                      97,
+                      117,
+                      108,
+                      116
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "escrow",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      101,
+                      115,
+                      99,
+                      114,
+                      111,
+                      119
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "produce"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "produce.settlement_mint",
+                    "account": "produce"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "mint"
+            },
+            {
+              "name": "treasuryVault",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      116,
+                      114,
+                      101,
+                      97,
+                      115,
+                      117,
+                      114,
+                      121,
+                      95,
+                      118,
+                      97,
+                      117,
+                      108,
+                      116
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "produce.settlement_mint",
+                    "account": "produce"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "tokenProgram"
+            }
+          ]
+        },
+        {
+          "name": "invoicing",
+          "accounts": [
+            {
+              "name": "produce"
+            },
+            {
+              "name": "invoice",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      105,
+                      110,
+                      118,
+                      111,
+                      105,
+                      99,
+                      101
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "produce"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "invoiceCounter",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      105,
+                      110,
+                      118,
+                      111,
+                      105,
+                      99,
+                      101,
+                      95,
+                      99,
+                      111,
+                      117,
+                      110,
+                      116,
+                      101,
+                      114
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "processPaymentSol",
+      "discriminator": [
+        177,
+        28,
+        123,
+        191,
+        14,
+        93,
+        138,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "payment",
+          "accounts": [
+            {
+              "name": "produce",
+              "writable": true
+            },
+            {
+              "name": "solEscrow",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      115,
+                      111,
+                      108,
+                      95,
+                      101,
+                      115,
+                      99,
+                      114,
+                      111,
+                      119
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "produce"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "farmer",
+              "writable": true
+            },
+            {
+              "name": "transporter",
+              "writable": true
+            },
+            {
+              "name": "buyer",
+              "writable": true
+            },
+            {
+              "name": "treasury",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      116,
+                      114,
+                      101,
+                      97,
+                      115,
+                      117,
+                      114,
+                      121
+                    ]
+                  }
+                ]
+              }
+            }
+          ]
+        },
+        {
+          "name": "invoicing",
+          "accounts": [
+            {
+              "name": "produce"
+            },
+            {
+              "name": "invoice",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      105,
+                      110,
+                      118,
+                      111,
+                      105,
+                      99,
+                      101
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "produce"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "invoiceCounter",
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      105,
+                      110,
+                      118,
+                      111,
+                      105,
+                      99,
+                      101,
+                      95,
+                      99,
+                      111,
+                      117,
+                      110,
+                      116,
+                      101,
+                      114
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "purchaseReceivable",
+      "discriminator": [
+        161,
+        28,
+        127,
+        191,
+        146,
+        246,
+        201,
+        24
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "receivable",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  118,
+                  97,
+                  98,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "farmer",
+          "signer": true,
+          "relations": [
+            "produce"
+          ]
+        },
+        {
+          "name": "liquidityProvider",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "liquidityProviderAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidityProvider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "liquidityProviderTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "farmerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "purchasePrice",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "queueProposal",
+      "discriminator": [
+        168,
+        219,
+        139,
+        211,
+        205,
+        152,
+        125,
+        110
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "governanceProposal"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "raiseDispute",
+      "discriminator": [
+        41,
+        243,
+        1,
+        51,
+        150,
+        95,
+        246,
+        73
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "raiser",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              },
+              {
+                "kind": "account",
+                "path": "produce.dispute_count",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "disputeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "raiserReputation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  117,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raiser"
+              }
+            ]
+          }
+        },
+        {
+          "name": "raiserTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              },
+              {
+                "kind": "account",
+                "path": "produce.settlement_mint",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce.settlement_mint",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "disputeReason"
+            }
+          }
+        },
+        {
+          "name": "description",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "raiseDisputeSol",
+      "discriminator": [
+        240,
+        28,
+        36,
+        173,
+        172,
+        17,
+        170,
+        252
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "raiser",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              },
+              {
+                "kind": "account",
+                "path": "produce.dispute_count",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "disputeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "raiserReputation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  117,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "raiser"
+              }
+            ]
+          }
+        },
+        {
+          "name": "solEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "disputeReason"
+            }
+          }
+        },
+        {
+          "name": "description",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "recordDelivery",
+      "discriminator": [
+        72,
+        245,
+        251,
+        211,
+        129,
+        177,
+        93,
+        214
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "transporterAccount",
+          "writable": true
+        },
+        {
+          "name": "transporter",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recordPickup",
+      "discriminator": [
+        133,
+        238,
+        8,
+        81,
+        210,
+        250,
+        186,
+        73
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "transporterAccount",
+          "writable": true
+        },
+        {
+          "name": "transporterReputation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  117,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "transporter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "transporter",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "temperature",
+          "type": "i16"
+        },
+        {
+          "name": "humidity",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "registerParticipant",
+      "discriminator": [
+        248,
+        112,
+        38,
+        215,
+        226,
+        230,
+        249,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "participant",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reputation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  117,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  114,
+                  98,
+                  105,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "participantRole"
+            }
+          }
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "contactInfo",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "releaseMilestone",
+      "discriminator": [
+        56,
+        2,
+        199,
+        164,
+        184,
+        108,
+        167,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "farmerPaymentAccount",
+          "writable": true
+        },
+        {
+          "name": "transporterPaymentAccount",
+          "writable": true
+        },
+        {
+          "name": "buyerPaymentAccount",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              },
+              {
+                "kind": "account",
+                "path": "produce.settlement_mint",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "treasuryVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce.settlement_mint",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "milestone",
+          "type": {
+            "defined": {
+              "name": "milestone"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseMilestoneSol",
+      "discriminator": [
+        67,
+        0,
+        132,
+        152,
+        187,
+        190,
+        19,
+        117
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "solEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "farmer",
+          "writable": true
+        },
+        {
+          "name": "transporter",
+          "writable": true
+        },
+        {
+          "name": "buyer",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "milestone",
+          "type": {
+            "defined": {
+              "name": "milestone"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "requestUnderwritingWithdrawal",
+      "discriminator": [
+        134,
+        239,
+        182,
+        58,
+        50,
+        210,
+        86,
+        123
+      ],
+      "accounts": [
+        {
+          "name": "staker",
+          "signer": true
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  110,
+                  100,
+                  101,
+                  114,
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "staker"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "shares",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "resolveDispute",
+      "discriminator": [
+        231,
+        6,
+        202,
+        6,
+        96,
+        103,
+        12,
+        230
+      ],
+      "accounts": [
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "produce",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "disputeConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "arbitratorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "arbitrator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  114,
+                  98,
+                  105,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "arbitrator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "resolution",
+          "type": "bool"
+        },
+        {
+          "name": "split",
+          "type": {
+            "defined": {
+              "name": "settlementSplit"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "revokeInspector",
+      "discriminator": [
+        190,
+        151,
+        223,
+        248,
+        163,
+        124,
+        139,
+        154
+      ],
+      "accounts": [
+        {
+          "name": "certification",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  112,
+                  101,
+                  99,
+                  116,
+                  111,
+                  114,
+                  95,
+                  99,
+                  101,
+                  114,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "certification.inspector",
+                "account": "inspectorCertification"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setGovernanceElectorate",
+      "discriminator": [
+        86,
+        6,
+        32,
+        155,
+        33,
+        54,
+        139,
+        57
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "roleWeights",
+          "type": {
+            "array": [
+              "u16",
+              8
+            ]
+          }
+        },
+        {
+          "name": "eligibleRoles",
+          "type": {
+            "array": [
+              "u8",
+              3
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMilestoneSchedule",
+      "discriminator": [
+        204,
+        81,
+        118,
+        232,
+        216,
+        62,
+        154,
+        27
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "farmer",
+          "signer": true,
+          "relations": [
+            "produce"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneBps",
+          "type": {
+            "array": [
+              "u16",
+              3
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setUnitPrice",
+      "discriminator": [
+        156,
+        156,
+        172,
+        92,
+        136,
+        109,
+        88,
+        26
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "farmer",
+          "signer": true,
+          "relations": [
+            "produce"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pricePerUnit",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "stakeTokens",
+      "discriminator": [
+        136,
+        126,
+        91,
+        162,
+        40,
+        131,
+        13,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "staker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "stakeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "staker"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "stakeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "submitEvidence",
+      "discriminator": [
+        12,
+        169,
+        228,
+        194,
+        229,
+        31,
+        44,
+        39
+      ],
+      "accounts": [
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "produce",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "evidence",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  105,
+                  100,
+                  101,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "dispute.evidence_count",
+                "account": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "submitter",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "contentHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "suspendArbitrator",
+      "discriminator": [
+        43,
+        68,
+        250,
+        181,
+        110,
+        134,
+        3,
+        47
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  114,
+                  98,
+                  105,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "arbitrator",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "timeoutDispute",
+      "discriminator": [
+        19,
+        170,
+        251,
+        157,
+        113,
+        142,
+        68,
+        143
+      ],
+      "accounts": [
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  114,
+                  98,
+                  105,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "undelegateVotes",
+      "discriminator": [
+        3,
+        16,
+        78,
+        162,
+        64,
+        40,
+        201,
+        107
+      ],
+      "accounts": [
+        {
+          "name": "delegatorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegateAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "delegate_account.owner",
+                "account": "participant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegator",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "underwriteInsurance",
+      "discriminator": [
+        221,
+        158,
+        137,
+        164,
+        121,
+        99,
+        89,
+        53
+      ],
+      "accounts": [
+        {
+          "name": "staker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "stakeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "staker"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  110,
+                  100,
+                  101,
+                  114,
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "staker"
+              }
+            ]
+          }
+        },
+        {
+          "name": "insurancePool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "insuranceVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "unstakeTokens",
+      "discriminator": [
+        58,
+        119,
+        215,
+        143,
+        203,
+        223,
+        32,
+        86
+      ],
+      "accounts": [
+        {
+          "name": "staker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "stakeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "staker"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "stakeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateDisputeConfig",
+      "discriminator": [
+        35,
+        236,
+        172,
+        134,
+        151,
+        139,
+        134,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "disputeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "disputeParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateGovernanceConfig",
+      "discriminator": [
+        140,
+        45,
+        181,
+        17,
+        77,
+        67,
+        157,
+        248
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "votingPeriod",
+          "type": "i64"
+        },
+        {
+          "name": "quorum",
+          "type": "u64"
+        },
+        {
+          "name": "minProposerStake",
+          "type": "u64"
+        },
+        {
+          "name": "timelock",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateInsurancePool",
+      "discriminator": [
+        202,
+        142,
+        204,
+        69,
+        142,
+        173,
+        248,
+        98
+      ],
+      "accounts": [
+        {
+          "name": "insurancePool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "premiumBps",
+          "type": "u16"
+        },
+        {
+          "name": "coverageBps",
+          "type": {
+            "array": [
+              "u16",
+              2
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePaymentPolicy",
+      "discriminator": [
+        222,
+        126,
+        18,
+        32,
+        21,
+        10,
+        33,
+        41
+      ],
+      "accounts": [
+        {
+          "name": "paymentPolicy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  121,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  112,
+                  111,
+                  108,
+                  105,
+                  99,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "shrinkageToleranceBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateQualityConfig",
+      "discriminator": [
+        72,
+        190,
+        52,
+        190,
+        40,
+        52,
+        205,
+        124
+      ],
+      "accounts": [
+        {
+          "name": "qualityConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  113,
+                  117,
+                  97,
+                  108,
+                  105,
+                  116,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "certifiedThreshold",
+          "type": "u64"
+        },
+        {
+          "name": "minQuality",
+          "type": "u8"
+        },
+        {
+          "name": "inspectionFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateTreasuryFees",
+      "discriminator": [
+        13,
+        56,
+        217,
+        239,
+        31,
+        177,
+        91,
+        25
+      ],
+      "accounts": [
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "settlementFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "bondFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "verifyQuality",
+      "discriminator": [
+        53,
+        102,
+        132,
+        199,
+        27,
+        42,
+        97,
+        41
+      ],
+      "accounts": [
+        {
+          "name": "produce",
+          "writable": true
+        },
+        {
+          "name": "inspection",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  112,
+                  101,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce"
+              },
+              {
+                "kind": "account",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifierAccount",
+          "writable": true
+        },
+        {
+          "name": "certification",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  112,
+                  101,
+                  99,
+                  116,
+                  111,
+                  114,
+                  95,
+                  99,
+                  101,
+                  114,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verifier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "qualityConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  113,
+                  117,
+                  97,
+                  108,
+                  105,
+                  116,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "farmerReputation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  112,
+                  117,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "produce.farmer",
+                "account": "produce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "verifier",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "verifiedQuality",
+          "type": "u8"
+        },
+        {
+          "name": "grades",
+          "type": {
+            "defined": {
+              "name": "qualityGrades"
+            }
+          }
+        },
+        {
+          "name": "labReportHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "voteProposal",
+      "discriminator": [
+        247,
+        104,
+        114,
+        240,
+        237,
+        41,
+        200,
+        36
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "governanceProposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal"
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voterAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  97,
+                  114,
+                  116,
+                  105,
+                  99,
+                  105,
+                  112,
+                  97,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  114,
+                  98,
+                  105,
+                  116,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "voter",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        },
+        {
+          "name": "choice",
+          "type": {
+            "defined": {
+              "name": "voteChoice"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawUnderwriting",
+      "discriminator": [
+        157,
+        126,
+        177,
+        79,
+        222,
+        180,
+        174,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "staker",
+          "signer": true
+        },
+        {
+          "name": "stakeAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "staker"
+              }
+            ]
+          }
+        },
+        {
+          "name": "position",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  117,
+                  110,
+                  100,
+                  101,
+                  114,
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "staker"
+              }
+            ]
+          }
+        },
+        {
+          "name": "insurancePool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "insuranceVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  97,
+                  107,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "shares",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawVote",
+      "discriminator": [
+        243,
+        255,
+        70,
+        200,
+        3,
+        242,
+        103,
+        137
+      ],
+      "accounts": [
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "governanceProposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal"
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voter",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "voteRecord"
+          ]
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "arbitratorRegistry",
+      "discriminator": [
+        252,
+        241,
+        152,
+        150,
+        74,
+        0,
+        119,
+        175
+      ]
+    },
+    {
+      "name": "dispute",
+      "discriminator": [
+        36,
+        49,
+        241,
+        67,
+        40,
+        36,
+        241,
+        74
+      ]
+    },
+    {
+      "name": "disputeConfig",
+      "discriminator": [
+        230,
+        88,
+        200,
+        99,
+        12,
+        93,
+        56,
+        156
+      ]
+    },
+    {
+      "name": "evidence",
+      "discriminator": [
+        160,
+        73,
+        93,
+        206,
+        99,
+        242,
+        62,
+        92
+      ]
+    },
+    {
+      "name": "governanceConfig",
+      "discriminator": [
+        81,
+        63,
+        124,
+        107,
+        210,
+        100,
+        145,
+        70
+      ]
+    },
+    {
+      "name": "governanceProposal",
+      "discriminator": [
+        53,
+        107,
+        240,
+        190,
+        43,
+        73,
+        65,
+        143
+      ]
+    },
+    {
+      "name": "inspectorCertification",
+      "discriminator": [
+        198,
+        231,
+        178,
+        118,
+        144,
+        52,
+        238,
+        155
+      ]
+    },
+    {
+      "name": "insurancePolicy",
+      "discriminator": [
+        171,
+        170,
+        55,
+        125,
+        71,
+        125,
+        63,
+        48
+      ]
+    },
+    {
+      "name": "insurancePool",
+      "discriminator": [
+        239,
+        152,
+        145,
+        201,
+        228,
+        155,
+        139,
+        140
+      ]
+    },
+    {
+      "name": "invoice",
+      "discriminator": [
+        51,
+        194,
+        250,
+        114,
+        6,
+        104,
+        18,
+        164
+      ]
+    },
+    {
+      "name": "invoiceCounter",
+      "discriminator": [
+        141,
+        34,
+        216,
+        15,
+        170,
+        248,
+        14,
+        41
+      ]
+    },
+    {
+      "name": "mintAllowlist",
+      "discriminator": [
+        155,
+        85,
+        58,
+        143,
+        107,
+        199,
+        161,
+        198
+      ]
+    },
+    {
+      "name": "participant",
+      "discriminator": [
+        32,
+        142,
+        108,
+        79,
+        247,
+        179,
+        54,
+        6
+      ]
+    },
+    {
+      "name": "paymentPolicy",
+      "discriminator": [
+        48,
+        74,
+        183,
+        94,
+        41,
+        92,
+        52,
+        44
+      ]
+    },
+    {
+      "name": "produce",
+      "discriminator": [
+        202,
+        115,
+        118,
+        182,
+        195,
+        125,
+        51,
+        61
+      ]
+    },
+    {
+      "name": "qualityConfig",
+      "discriminator": [
+        68,
+        129,
+        128,
+        139,
+        255,
+        78,
+        186,
+        31
+      ]
+    },
+    {
+      "name": "qualityInspection",
+      "discriminator": [
+        197,
+        191,
+        186,
+        19,
+        220,
+        157,
+        76,
+        116
+      ]
+    },
+    {
+      "name": "receivable",
+      "discriminator": [
+        251,
+        233,
+        56,
+        203,
+        43,
+        43,
+        80,
+        4
+      ]
+    },
+    {
+      "name": "reputation",
+      "discriminator": [
+        55,
+        148,
+        90,
+        71,
+        68,
+        183,
+        193,
+        28
+      ]
+    },
+    {
+      "name": "solEscrow",
+      "discriminator": [
+        75,
+        199,
+        250,
+        63,
+        244,
+        209,
+        235,
+        120
+      ]
+    },
+    {
+      "name": "stakeAccount",
+      "discriminator": [
+        80,
+        158,
+        67,
+        124,
+        50,
+        189,
+        192,
+        255
+      ]
+    },
+    {
+      "name": "treasury",
+      "discriminator": [
+        238,
+        239,
+        123,
+        238,
+        89,
+        1,
+        168,
+        253
+      ]
+    },
+    {
+      "name": "underwriterPosition",
+      "discriminator": [
+        33,
+        87,
+        62,
+        126,
+        208,
+        123,
+        202,
+        22
+      ]
+    },
+    {
+      "name": "vault",
       "discriminator": [
         211,
         8,
         232,
         43,
         2,
-        152,
-        117,
-        119
+        152,
+        117,
+        119
+      ]
+    },
+    {
+      "name": "voteRecord",
+      "discriminator": [
+        112,
+        9,
+        123,
+        165,
+        234,
+        9,
+        157,
+        167
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "arbitrationFeesClaimed",
+      "discriminator": [
+        3,
+        22,
+        175,
+        172,
+        133,
+        159,
+        8,
+        81
+      ]
+    },
+    {
+      "name": "arbitratorStatusChanged",
+      "discriminator": [
+        136,
+        52,
+        186,
+        140,
+        38,
+        20,
+        178,
+        206
+      ]
+    },
+    {
+      "name": "batchInsured",
+      "discriminator": [
+        157,
+        65,
+        92,
+        35,
+        119,
+        216,
+        82,
+        147
+      ]
+    },
+    {
+      "name": "deliveryReceived",
+      "discriminator": [
+        84,
+        156,
+        211,
+        37,
+        207,
+        1,
+        105,
+        161
+      ]
+    },
+    {
+      "name": "disputeAnswered",
+      "discriminator": [
+        185,
+        203,
+        53,
+        172,
+        148,
+        51,
+        155,
+        151
+      ]
+    },
+    {
+      "name": "disputeAppealed",
+      "discriminator": [
+        88,
+        78,
+        239,
+        142,
+        115,
+        181,
+        200,
+        69
+      ]
+    },
+    {
+      "name": "disputeRaised",
+      "discriminator": [
+        246,
+        167,
+        109,
+        37,
+        142,
+        45,
+        38,
+        176
+      ]
+    },
+    {
+      "name": "disputeResolved",
+      "discriminator": [
+        121,
+        64,
+        249,
+        153,
+        139,
+        128,
+        236,
+        187
+      ]
+    },
+    {
+      "name": "disputeRuled",
+      "discriminator": [
+        20,
+        51,
+        107,
+        231,
+        74,
+        70,
+        199,
+        228
+      ]
+    },
+    {
+      "name": "disputeTimedOut",
+      "discriminator": [
+        137,
+        64,
+        237,
+        240,
+        254,
+        236,
+        189,
+        185
+      ]
+    },
+    {
+      "name": "evidenceSubmitted",
+      "discriminator": [
+        13,
+        123,
+        197,
+        44,
+        231,
+        117,
+        168,
+        53
+      ]
+    },
+    {
+      "name": "harvestLogged",
+      "discriminator": [
+        120,
+        78,
+        199,
+        142,
+        215,
+        123,
+        47,
+        146
+      ]
+    },
+    {
+      "name": "inspectionFeesClaimed",
+      "discriminator": [
+        185,
+        37,
+        174,
+        215,
+        82,
+        3,
+        63,
+        112
+      ]
+    },
+    {
+      "name": "inspectorCertificationChanged",
+      "discriminator": [
+        164,
+        182,
+        138,
+        238,
+        177,
+        221,
+        131,
+        12
+      ]
+    },
+    {
+      "name": "insuranceClaimPaid",
+      "discriminator": [
+        192,
+        156,
+        171,
+        111,
+        107,
+        227,
+        78,
+        164
+      ]
+    },
+    {
+      "name": "milestoneReleased",
+      "discriminator": [
+        49,
+        225,
+        91,
+        223,
+        34,
+        165,
+        109,
+        181
+      ]
+    },
+    {
+      "name": "mintAllowlistChanged",
+      "discriminator": [
+        90,
+        57,
+        118,
+        152,
+        181,
+        239,
+        144,
+        70
+      ]
+    },
+    {
+      "name": "panelAssigned",
+      "discriminator": [
+        17,
+        137,
+        224,
+        101,
+        54,
+        150,
+        145,
+        87
+      ]
+    },
+    {
+      "name": "panelRulingSubmitted",
+      "discriminator": [
+        245,
+        138,
+        101,
+        197,
+        19,
+        185,
+        150,
+        50
+      ]
+    },
+    {
+      "name": "proposalCreated",
+      "discriminator": [
+        186,
+        8,
+        160,
+        108,
+        81,
+        13,
+        51,
+        206
+      ]
+    },
+    {
+      "name": "proposalFinalized",
+      "discriminator": [
+        159,
+        104,
+        210,
+        220,
+        86,
+        209,
+        61,
+        51
+      ]
+    },
+    {
+      "name": "protocolFeeCollected",
+      "discriminator": [
+        149,
+        0,
+        167,
+        154,
+        105,
+        146,
+        209,
+        134
+      ]
+    },
+    {
+      "name": "qualityInspected",
+      "discriminator": [
+        215,
+        173,
+        149,
+        165,
+        246,
+        82,
+        7,
+        33
+      ]
+    },
+    {
+      "name": "receivablePurchased",
+      "discriminator": [
+        184,
+        151,
+        249,
+        157,
+        147,
+        23,
+        124,
+        137
+      ]
+    },
+    {
+      "name": "settlementCompleted",
+      "discriminator": [
+        24,
+        33,
+        148,
+        69,
+        53,
+        1,
+        59,
+        48
+      ]
+    },
+    {
+      "name": "treasurySpent",
+      "discriminator": [
+        70,
+        150,
+        242,
+        53,
+        19,
+        121,
+        15,
+        88
+      ]
+    },
+    {
+      "name": "underwritingChanged",
+      "discriminator": [
+        45,
+        7,
+        237,
+        81,
+        189,
+        41,
+        175,
+        123
+      ]
+    },
+    {
+      "name": "underwritingWithdrawalRequested",
+      "discriminator": [
+        229,
+        240,
+        134,
+        155,
+        48,
+        44,
+        192,
+        192
+      ]
+    },
+    {
+      "name": "votesDelegated",
+      "discriminator": [
+        161,
+        23,
+        128,
+        137,
+        139,
+        178,
+        108,
+        25
+      ]
+    },
+    {
+      "name": "votesUndelegated",
+      "discriminator": [
+        2,
+        219,
+        56,
+        161,
+        252,
+        235,
+        221,
+        36
       ]
     }
   ],
-  "events": [
+  "errors": [
+    {
+      "code": 6000,
+      "name": "nameTooLong",
+      "msg": "Name exceeds maximum length of 32 characters"
+    },
+    {
+      "code": 6001,
+      "name": "contactInfoTooLong",
+      "msg": "Contact info exceeds maximum length of 64 characters"
+    }
+  ],
+  "types": [
+    {
+      "name": "arbitrationFeesClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "arbitrator",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "arbitratorEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "arbitrator",
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "arbitratorStatus"
+              }
+            }
+          },
+          {
+            "name": "caseCount",
+            "type": "u32"
+          },
+          {
+            "name": "appointedAt",
+            "type": "i64"
+          },
+          {
+            "name": "majorityCount",
+            "type": "u32"
+          },
+          {
+            "name": "dissentCount",
+            "type": "u32"
+          },
+          {
+            "name": "timeoutCount",
+            "type": "u32"
+          },
+          {
+            "name": "feesEarned",
+            "type": "u64"
+          },
+          {
+            "name": "feesUnclaimed",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "arbitratorRegistry",
+      "docs": [
+        "Arbitrators appointed by the admin or by executed governance proposals.",
+        "Only active entries may rule on disputes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "arbitrators",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "arbitratorEntry"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "arbitratorStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "active"
+          },
+          {
+            "name": "suspended"
+          }
+        ]
+      }
+    },
+    {
+      "name": "arbitratorStatusChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "arbitrator",
+            "type": "pubkey"
+          },
+          {
+            "name": "active",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "batchInsured",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
+            "type": "pubkey"
+          },
+          {
+            "name": "insured",
+            "type": "pubkey"
+          },
+          {
+            "name": "insuredValue",
+            "type": "u64"
+          },
+          {
+            "name": "premium",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimReason",
+      "docs": [
+        "Losses the insurance pool covers."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "spoilage"
+          },
+          {
+            "name": "coldChain"
+          }
+        ]
+      }
+    },
+    {
+      "name": "deliveryReceived",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produceId",
+            "type": "u64"
+          },
+          {
+            "name": "receivedQuantity",
+            "type": "u64"
+          },
+          {
+            "name": "transitLossCharge",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "dispute",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "raiser",
+            "type": "pubkey"
+          },
+          {
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "resolved",
+            "type": "bool"
+          },
+          {
+            "name": "resolution",
+            "type": "bool"
+          },
+          {
+            "name": "split",
+            "type": {
+              "defined": {
+                "name": "settlementSplit"
+              }
+            }
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "disputeReason"
+              }
+            }
+          },
+          {
+            "name": "bondAmount",
+            "type": "u64"
+          },
+          {
+            "name": "evidenceCount",
+            "type": "u32"
+          },
+          {
+            "name": "evidenceDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "panelRequired",
+            "type": "bool"
+          },
+          {
+            "name": "panel",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "rulings",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "panelRuling"
+                }
+              }
+            }
+          },
+          {
+            "name": "dissenters",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "ruledAt",
+            "type": "i64"
+          },
+          {
+            "name": "appealDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "settled",
+            "type": "bool"
+          },
+          {
+            "name": "appealRound",
+            "type": "u8"
+          },
+          {
+            "name": "appellant",
+            "type": "pubkey"
+          },
+          {
+            "name": "appealBond",
+            "type": "u64"
+          },
+          {
+            "name": "appealedResolution",
+            "type": "bool"
+          },
+          {
+            "name": "priorArbitrators",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "arbitrationFee",
+            "type": "u64"
+          },
+          {
+            "name": "rulingDueAt",
+            "type": "i64"
+          },
+          {
+            "name": "timedOut",
+            "type": "bool"
+          },
+          {
+            "name": "feeCollected",
+            "type": "u64"
+          },
+          {
+            "name": "feeClaimants",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "responseBond",
+            "type": "u64"
+          },
+          {
+            "name": "escrowShare",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeAnswered",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "respondent",
+            "type": "pubkey"
+          },
+          {
+            "name": "responseBond",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeAppealed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "appellant",
+            "type": "pubkey"
+          },
+          {
+            "name": "appealBond",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeConfig",
+      "docs": [
+        "Dispute parameters set by the governance admin."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "params",
+            "type": {
+              "defined": {
+                "name": "disputeParams"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeParams",
+      "docs": [
+        "Tunable dispute parameters."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondAmount",
+            "type": "u64"
+          },
+          {
+            "name": "evidencePeriod",
+            "type": "i64"
+          },
+          {
+            "name": "panelThreshold",
+            "type": "u64"
+          },
+          {
+            "name": "panelSize",
+            "type": "u8"
+          },
+          {
+            "name": "appealWindow",
+            "type": "i64"
+          },
+          {
+            "name": "appealBond",
+            "type": "u64"
+          },
+          {
+            "name": "arbitrationFee",
+            "type": "u64"
+          },
+          {
+            "name": "maxRulingTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeRaised",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
+            "type": "pubkey"
+          },
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "raiser",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "disputeReason"
+              }
+            }
+          },
+          {
+            "name": "bondAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeReason",
+      "docs": [
+        "Category of the problem behind a dispute."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "quality"
+          },
+          {
+            "name": "spoilage"
+          },
+          {
+            "name": "coldChain"
+          },
+          {
+            "name": "quantity"
+          },
+          {
+            "name": "lateDelivery"
+          },
+          {
+            "name": "nonDelivery"
+          },
+          {
+            "name": "payment"
+          },
+          {
+            "name": "other"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeResolved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
+            "type": "pubkey"
+          },
+          {
+            "name": "resolution",
+            "type": "bool"
+          },
+          {
+            "name": "farmerAmount",
+            "type": "u64"
+          },
+          {
+            "name": "transporterAmount",
+            "type": "u64"
+          },
+          {
+            "name": "buyerRefund",
+            "type": "u64"
+          },
+          {
+            "name": "bondRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "appealBondRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "responseBondRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "arbitrationFee",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeRuled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "resolution",
+            "type": "bool"
+          },
+          {
+            "name": "appealDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "disputeTimedOut",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "flaggedArbitrators",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "evidence",
+      "docs": [
+        "A piece of evidence attached to a dispute. The content itself (photo, lab",
+        "report) lives off-chain at `uri`; `content_hash` pins its exact contents."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "submitter",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "submittedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "evidenceSubmitted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "submitter",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "governanceConfig",
+      "docs": [
+        "Global governance parameters and the proposal counter."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "votingPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "quorum",
+            "type": "u64"
+          },
+          {
+            "name": "minProposerStake",
+            "type": "u64"
+          },
+          {
+            "name": "timelock",
+            "type": "i64"
+          },
+          {
+            "name": "proposalCount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "roleWeights",
+            "type": {
+              "array": [
+                "u16",
+                8
+              ]
+            }
+          },
+          {
+            "name": "eligibleRoles",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "governanceProposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposalType",
+            "type": {
+              "defined": {
+                "name": "proposalType"
+              }
+            }
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "proposalAction"
+              }
+            }
+          },
+          {
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "name": "votesFor",
+            "type": "u64"
+          },
+          {
+            "name": "votesAgainst",
+            "type": "u64"
+          },
+          {
+            "name": "votesAbstain",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "proposalStatus"
+              }
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "votingEndsAt",
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "harvestLogged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produceId",
+            "type": "u64"
+          },
+          {
+            "name": "farmer",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inspectionFeesClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "inspector",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inspectorCertification",
+      "docs": [
+        "Marks a participant as a certified inspector. Granted by the governance",
+        "admin or by an executed governance proposal."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "inspector",
+            "type": "pubkey"
+          },
+          {
+            "name": "certifiedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "certifiedAt",
+            "type": "i64"
+          },
+          {
+            "name": "active",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "feesEarned",
+            "type": "u64"
+          },
+          {
+            "name": "feesUnclaimed",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inspectorCertificationChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "inspector",
+            "type": "pubkey"
+          },
+          {
+            "name": "active",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "insuranceClaimPaid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
+            "type": "pubkey"
+          },
+          {
+            "name": "insured",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "claimReason"
+              }
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "outstanding",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "insurancePolicy",
+      "docs": [
+        "Cover bought for one batch."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
+            "type": "pubkey"
+          },
+          {
+            "name": "insured",
+            "type": "pubkey"
+          },
+          {
+            "name": "insuredValue",
+            "type": "u64"
+          },
+          {
+            "name": "premium",
+            "type": "u64"
+          },
+          {
+            "name": "claimed",
+            "type": "bool"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "claimAmount",
+            "type": "u64"
+          },
+          {
+            "name": "claimPaid",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "insurancePool",
+      "docs": [
+        "Optional crop insurance, underwritten by stakers in proportion to their",
+        "shares."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "premiumBps",
+            "type": "u16"
+          },
+          {
+            "name": "coverageBps",
+            "type": {
+              "array": [
+                "u16",
+                2
+              ]
+            }
+          },
+          {
+            "name": "totalShares",
+            "type": "u64"
+          },
+          {
+            "name": "premiumsCollected",
+            "type": "u64"
+          },
+          {
+            "name": "claimsPaid",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "claimsOutstanding",
+            "type": "u64"
+          },
+          {
+            "name": "balance",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "invoice",
+      "docs": [
+        "Accounting record of a settled batch; line items add up to `total`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
+            "type": "pubkey"
+          },
+          {
+            "name": "produceId",
+            "type": "u64"
+          },
+          {
+            "name": "sequence",
+            "type": "u64"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "farmer",
+            "type": "pubkey"
+          },
+          {
+            "name": "transporter",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "quantity",
+            "type": "u64"
+          },
+          {
+            "name": "receivedQuantity",
+            "type": "u64"
+          },
+          {
+            "name": "pricePerUnit",
+            "type": "u64"
+          },
+          {
+            "name": "lineItems",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "lineItem"
+                }
+              }
+            }
+          },
+          {
+            "name": "total",
+            "type": "u64"
+          },
+          {
+            "name": "buyerRefund",
+            "type": "u64"
+          },
+          {
+            "name": "harvestDate",
+            "type": "i64"
+          },
+          {
+            "name": "pickedUpAt",
+            "type": "i64"
+          },
+          {
+            "name": "issuedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "invoiceCounter",
+      "docs": [
+        "Numbers invoices in the order batches settle."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nextSequence",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "lineItem",
+      "docs": [
+        "One charge on an invoice. `amount` is negative for deductions."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "lineItemKind"
+              }
+            }
+          },
+          {
+            "name": "party",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "lineItemKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "goods"
+          },
+          {
+            "name": "freight"
+          },
+          {
+            "name": "transitLoss"
+          },
+          {
+            "name": "qualityBonus"
+          },
+          {
+            "name": "qualityPenalty"
+          },
+          {
+            "name": "inspectionFees"
+          },
+          {
+            "name": "protocolFee"
+          }
+        ]
+      }
+    },
+    {
+      "name": "milestone",
+      "docs": [
+        "Points in a batch's journey at which part of the escrow is released."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "pickup"
+          },
+          {
+            "name": "delivery"
+          },
+          {
+            "name": "quality"
+          }
+        ]
+      }
+    },
+    {
+      "name": "milestoneReleased",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
+            "type": "pubkey"
+          },
+          {
+            "name": "milestone",
+            "type": {
+              "defined": {
+                "name": "milestone"
+              }
+            }
+          },
+          {
+            "name": "farmerAmount",
+            "type": "u64"
+          },
+          {
+            "name": "transporterAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintAllowlist",
+      "docs": [
+        "Stablecoin mints batches may be settled in, managed by governance."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mints",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintAllowlistChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "allowed",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "panelAssigned",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "panel",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "panelRuling",
+      "docs": [
+        "One panel member's ruling."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "arbitrator",
+            "type": "pubkey"
+          },
+          {
+            "name": "resolution",
+            "type": "bool"
+          },
+          {
+            "name": "split",
+            "type": {
+              "defined": {
+                "name": "settlementSplit"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "panelRulingSubmitted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "arbitrator",
+            "type": "pubkey"
+          },
+          {
+            "name": "resolution",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "participant",
+      "docs": [
+        "Participant account that stores identity details."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "participantRole"
+              }
+            }
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "contactInfo",
+            "type": "string"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegatedByRole",
+            "type": {
+              "array": [
+                "u16",
+                8
+              ]
+            }
+          },
+          {
+            "name": "voteLockedUntil",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "participantRole",
+      "docs": [
+        "Defines the different participant roles."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "farmer"
+          },
+          {
+            "name": "transporter"
+          },
+          {
+            "name": "wholesaler"
+          },
+          {
+            "name": "retailer"
+          },
+          {
+            "name": "arbitrator"
+          },
+          {
+            "name": "inspector"
+          },
+          {
+            "name": "liquidityProvider"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paymentPolicy",
+      "docs": [
+        "Program-wide settlement policy, managed by the governance admin."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "shrinkageToleranceBps",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "produce",
+      "docs": [
+        "The Produce account tracks a batch from harvest to market."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produceId",
+            "type": "u64"
+          },
+          {
+            "name": "farmer",
+            "type": "pubkey"
+          },
+          {
+            "name": "produceType",
+            "type": "string"
+          },
+          {
+            "name": "quantity",
+            "type": "u64"
+          },
+          {
+            "name": "harvestDate",
+            "type": "i64"
+          },
+          {
+            "name": "quality",
+            "type": "u8"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "produceStatus"
+              }
+            }
+          },
+          {
+            "name": "lastUpdated",
+            "type": "i64"
+          },
+          {
+            "name": "transportTemp",
+            "type": "i16"
+          },
+          {
+            "name": "transportHumidity",
+            "type": "u8"
+          },
+          {
+            "name": "pickupConfirmed",
+            "type": "bool"
+          },
+          {
+            "name": "deliveryConfirmed",
+            "type": "bool"
+          },
+          {
+            "name": "disputeRaised",
+            "type": "bool"
+          },
+          {
+            "name": "verifiedQuality",
+            "type": "u8"
+          },
+          {
+            "name": "qrCodeUri",
+            "type": "string"
+          },
+          {
+            "name": "farmerPrice",
+            "type": "u64"
+          },
+          {
+            "name": "transporterFee",
+            "type": "u64"
+          },
+          {
+            "name": "transporter",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "escrowedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "qualityVerifier",
+            "type": "pubkey"
+          },
+          {
+            "name": "disputeCount",
+            "type": "u32"
+          },
+          {
+            "name": "openDisputes",
+            "type": "u32"
+          },
+          {
+            "name": "pickedUpAt",
+            "type": "i64"
+          },
+          {
+            "name": "inspectionScores",
+            "type": "bytes"
+          },
+          {
+            "name": "certifiedInspections",
+            "type": "u8"
+          },
+          {
+            "name": "inspectorScores",
+            "type": "bytes"
+          },
+          {
+            "name": "settlementMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "milestoneBps",
+            "type": {
+              "array": [
+                "u16",
+                3
+              ]
+            }
+          },
+          {
+            "name": "milestonesReleased",
+            "type": "u8"
+          },
+          {
+            "name": "farmerReleased",
+            "type": "u64"
+          },
+          {
+            "name": "transporterReleased",
+            "type": "u64"
+          },
+          {
+            "name": "pricePerUnit",
+            "type": "u64"
+          },
+          {
+            "name": "receivedQuantity",
+            "type": "u64"
+          },
+          {
+            "name": "transitLossCharge",
+            "type": "u64"
+          },
+          {
+            "name": "inspectionFees",
+            "type": "u64"
+          },
+          {
+            "name": "receivableOwner",
+            "type": "pubkey"
+          },
+          {
+            "name": "protocolFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "protocolFees",
+            "type": "u64"
+          },
+          {
+            "name": "inspectionFee",
+            "type": "u64"
+          },
+          {
+            "name": "disputedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "settled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "produceStatus",
+      "docs": [
+        "Different states of a produce batch."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "harvested"
+          },
+          {
+            "name": "pickedUp"
+          },
+          {
+            "name": "inTransit"
+          },
+          {
+            "name": "delivered"
+          },
+          {
+            "name": "qualityVerified"
+          },
+          {
+            "name": "disputed"
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposalAction",
+      "docs": [
+        "On-chain effect applied when a proposal is executed. `None` is a signalling",
+        "proposal; other actions are executed by the instruction that owns the",
+        "affected state."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "none"
+          },
+          {
+            "name": "appointArbitrator",
+            "fields": [
+              {
+                "name": "arbitrator",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "suspendArbitrator",
+            "fields": [
+              {
+                "name": "arbitrator",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "certifyInspector",
+            "fields": [
+              {
+                "name": "inspector",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "revokeInspector",
+            "fields": [
+              {
+                "name": "inspector",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "allowMint",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "disallowMint",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "treasurySpend",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "pubkey"
+              },
+              {
+                "name": "recipient",
+                "type": "pubkey"
+              },
+              {
+                "name": "amount",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposalCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposalFinalized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "passed",
+            "type": "bool"
+          },
+          {
+            "name": "votesFor",
+            "type": "u64"
+          },
+          {
+            "name": "votesAgainst",
+            "type": "u64"
+          },
+          {
+            "name": "votesAbstain",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposalStatus",
+      "docs": [
+        "Lifecycle of a governance proposal."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "active"
+          },
+          {
+            "name": "passed"
+          },
+          {
+            "name": "rejected"
+          },
+          {
+            "name": "queued"
+          },
+          {
+            "name": "executed"
+          },
+          {
+            "name": "cancelled"
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposalType",
+      "docs": [
+        "Category of a proposal; each category has its own electorate."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "general"
+          },
+          {
+            "name": "pricingPolicy"
+          },
+          {
+            "name": "disputeRules"
+          }
+        ]
+      }
+    },
     {
-      "name": "disputeRaised",
-      "discriminator": [
-        246,
-        167,
-        109,
-        37,
-        142,
-        45,
-        38,
-        176
-      ]
+      "name": "protocolFeeCollected",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "harvestLogged",
-      "discriminator": [
-        120,
-        78,
-        199,
-        142,
-        215,
-        123,
-        47,
-        146
-      ]
+      "name": "qualityConfig",
+      "docs": [
+        "Program-wide inspection rules, set by the governance admin."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "certifiedThreshold",
+            "type": "u64"
+          },
+          {
+            "name": "minQuality",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "inspectionFee",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "proposalCreated",
-      "discriminator": [
-        186,
-        8,
-        160,
-        108,
-        81,
-        13,
-        51,
-        206
-      ]
-    }
-  ],
-  "errors": [
+      "name": "qualityGrades",
+      "docs": [
+        "Component grades behind an inspection score."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "size",
+            "type": "u8"
+          },
+          {
+            "name": "color",
+            "type": "u8"
+          },
+          {
+            "name": "defects",
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
-      "code": 6000,
-      "name": "nameTooLong",
-      "msg": "Name exceeds maximum length of 32 characters"
+      "name": "qualityInspected",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
+            "type": "pubkey"
+          },
+          {
+            "name": "inspector",
+            "type": "pubkey"
+          },
+          {
+            "name": "score",
+            "type": "u8"
+          },
+          {
+            "name": "certified",
+            "type": "bool"
+          },
+          {
+            "name": "effectiveQuality",
+            "type": "u8"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "contactInfoTooLong",
-      "msg": "Contact info exceeds maximum length of 64 characters"
-    }
-  ],
-  "types": [
+      "name": "qualityInspection",
+      "docs": [
+        "One inspector's assessment of a batch. Each inspector may inspect a batch",
+        "once, so earlier results cannot be overwritten."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
+            "type": "pubkey"
+          },
+          {
+            "name": "inspector",
+            "type": "pubkey"
+          },
+          {
+            "name": "score",
+            "type": "u8"
+          },
+          {
+            "name": "grades",
+            "type": {
+              "defined": {
+                "name": "qualityGrades"
+              }
+            }
+          },
+          {
+            "name": "labReportHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "certified",
+            "type": "bool"
+          },
+          {
+            "name": "inspectedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "independent",
+            "type": "bool"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "feeClaimed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
     {
-      "name": "dispute",
+      "name": "receivable",
+      "docs": [
+        "A farmer's claim on the unreleased payouts of a batch, sold to a",
+        "liquidity provider."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
@@ -1066,30 +10832,132 @@ export type DecentralizedAgSupply = {
             "type": "pubkey"
           },
           {
-            "name": "raiser",
+            "name": "farmer",
             "type": "pubkey"
           },
           {
-            "name": "description",
-            "type": "string"
+            "name": "liquidityProvider",
+            "type": "pubkey"
           },
           {
-            "name": "createdAt",
+            "name": "purchasePrice",
+            "type": "u64"
+          },
+          {
+            "name": "faceValue",
+            "type": "u64"
+          },
+          {
+            "name": "purchasedAt",
             "type": "i64"
           },
           {
-            "name": "resolved",
-            "type": "bool"
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "receivablePurchased",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
+            "type": "pubkey"
+          },
+          {
+            "name": "farmer",
+            "type": "pubkey"
+          },
+          {
+            "name": "liquidityProvider",
+            "type": "pubkey"
+          },
+          {
+            "name": "purchasePrice",
+            "type": "u64"
+          },
+          {
+            "name": "faceValue",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "reputation",
+      "docs": [
+        "Track record of a participant, maintained by the program as batches move",
+        "through the supply chain. Counters saturate rather than fail so that a",
+        "long history never blocks a shipment."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "deliveriesCompleted",
+            "type": "u32"
+          },
+          {
+            "name": "onTimeDeliveries",
+            "type": "u32"
+          },
+          {
+            "name": "qualityReports",
+            "type": "u32"
+          },
+          {
+            "name": "reportedQualityTotal",
+            "type": "u64"
+          },
+          {
+            "name": "verifiedQualityTotal",
+            "type": "u64"
+          },
+          {
+            "name": "overstatedReports",
+            "type": "u32"
+          },
+          {
+            "name": "coldChainExcursions",
+            "type": "u32"
+          },
+          {
+            "name": "disputesRaised",
+            "type": "u32"
+          },
+          {
+            "name": "disputesLost",
+            "type": "u32"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
           },
           {
-            "name": "resolution",
-            "type": "bool"
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "disputeRaised",
+      "name": "settlementCompleted",
+      "docs": [
+        "Final accounting of a batch. Adjustments are the quality and cold-chain",
+        "bonuses (positive) or penalties (negative) applied to the base amounts;",
+        "fees are reported net of any transfer fee."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
@@ -1098,78 +10966,100 @@ export type DecentralizedAgSupply = {
             "type": "pubkey"
           },
           {
-            "name": "raiser",
+            "name": "buyer",
             "type": "pubkey"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "governanceProposal",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "farmerBase",
+            "type": "u64"
+          },
           {
-            "name": "proposalId",
+            "name": "transporterBase",
             "type": "u64"
           },
           {
-            "name": "description",
-            "type": "string"
+            "name": "transitLossCharge",
+            "type": "u64"
           },
           {
-            "name": "votesFor",
+            "name": "farmerAdjustment",
+            "type": "i64"
+          },
+          {
+            "name": "transporterAdjustment",
+            "type": "i64"
+          },
+          {
+            "name": "farmerPaid",
             "type": "u64"
           },
           {
-            "name": "votesAgainst",
+            "name": "transporterPaid",
             "type": "u64"
           },
           {
-            "name": "executed",
-            "type": "bool"
+            "name": "protocolFees",
+            "type": "u64"
           },
           {
-            "name": "createdAt",
-            "type": "i64"
+            "name": "buyerRefund",
+            "type": "u64"
           },
           {
-            "name": "voters",
-            "type": {
-              "vec": "pubkey"
-            }
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "harvestLogged",
+      "name": "settlementSplit",
+      "docs": [
+        "How a batch's escrowed funds are divided when a dispute is resolved,",
+        "in basis points of the escrowed amount. The shares must sum to 10,000."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "produceId",
-            "type": "u64"
+            "name": "farmerBps",
+            "type": "u16"
           },
           {
-            "name": "farmer",
+            "name": "transporterBps",
+            "type": "u16"
+          },
+          {
+            "name": "buyerBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "solEscrow",
+      "docs": [
+        "Program-owned account holding a native SOL batch's escrowed lamports.",
+        "Everything above its rent-exempt minimum belongs to the batch."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "produce",
             "type": "pubkey"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "participant",
+      "name": "stakeAccount",
       "docs": [
-        "Participant account that stores identity details."
+        "Tracks how many tokens a wallet has locked in the stake vault."
       ],
       "type": {
         "kind": "struct",
@@ -1179,178 +11069,246 @@ export type DecentralizedAgSupply = {
             "type": "pubkey"
           },
           {
-            "name": "role",
-            "type": {
-              "defined": {
-                "name": "participantRole"
-              }
-            }
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "name",
-            "type": "string"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "contactInfo",
-            "type": "string"
+            "name": "lockedAmount",
+            "type": "u64"
           },
           {
-            "name": "createdAt",
+            "name": "lockedUntil",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "participantRole",
+      "name": "treasury",
       "docs": [
-        "Defines the different participant roles."
+        "Protocol treasury. Holds fees in a token account per accepted mint, and",
+        "fees on native SOL batches as lamports on this account. Funds leave it",
+        "only through executed `TreasurySpend` proposals."
       ],
       "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "farmer"
-          },
-          {
-            "name": "transporter"
-          },
+        "kind": "struct",
+        "fields": [
           {
-            "name": "wholesaler"
+            "name": "settlementFeeBps",
+            "type": "u16"
           },
           {
-            "name": "retailer"
+            "name": "bondFeeBps",
+            "type": "u16"
           },
           {
-            "name": "arbitrator"
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "produce",
-      "docs": [
-        "The Produce account tracks a batch from harvest to market."
-      ],
+      "name": "treasurySpent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "produceId",
+            "name": "proposalId",
             "type": "u64"
           },
           {
-            "name": "farmer",
+            "name": "mint",
             "type": "pubkey"
           },
           {
-            "name": "produceType",
-            "type": "string"
+            "name": "recipient",
+            "type": "pubkey"
           },
           {
-            "name": "quantity",
+            "name": "amount",
             "type": "u64"
           },
           {
-            "name": "harvestDate",
+            "name": "timestamp",
             "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "underwriterPosition",
+      "docs": [
+        "A staker's share of the insurance pool."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "quality",
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
             "type": "u8"
           },
           {
-            "name": "status",
-            "type": {
-              "defined": {
-                "name": "produceStatus"
-              }
-            }
+            "name": "pendingShares",
+            "type": "u64"
           },
           {
-            "name": "lastUpdated",
+            "name": "withdrawalRequestedAt",
             "type": "i64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "underwritingChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "transportTemp",
-            "type": "i16"
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "transportHumidity",
-            "type": "u8"
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "pickupConfirmed",
-            "type": "bool"
+            "name": "shares",
+            "type": "u64"
           },
           {
-            "name": "deliveryConfirmed",
+            "name": "deposited",
             "type": "bool"
           },
           {
-            "name": "disputeRaised",
-            "type": "bool"
-          },
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "underwritingWithdrawalRequested",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "verifiedQuality",
-            "type": "u8"
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "qrCodeUri",
-            "type": "string"
+            "name": "shares",
+            "type": "u64"
           },
           {
-            "name": "farmerPrice",
-            "type": "u64"
+            "name": "availableAt",
+            "type": "i64"
           },
           {
-            "name": "transporterFee",
-            "type": "u64"
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "produceStatus",
+      "name": "vault",
       "docs": [
-        "Different states of a produce batch."
+        "Signs for every batch escrow account."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteChoice",
+      "docs": [
+        "A voter's choice on a proposal. Abstentions count toward quorum only."
       ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "harvested"
+            "name": "for"
           },
           {
-            "name": "pickedUp"
+            "name": "against"
           },
           {
-            "name": "inTransit"
+            "name": "abstain"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteRecord",
+      "docs": [
+        "A single voter's ballot on a proposal."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposal",
+            "type": "pubkey"
           },
           {
-            "name": "delivered"
+            "name": "voter",
+            "type": "pubkey"
           },
           {
-            "name": "qualityVerified"
+            "name": "choice",
+            "type": {
+              "defined": {
+                "name": "voteChoice"
+              }
+            }
           },
           {
-            "name": "disputed"
+            "name": "weight",
+            "type": "u64"
+          },
+          {
+            "name": "votedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "proposalCreated",
+      "name": "votesDelegated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "proposalId",
-            "type": "u64"
+            "name": "delegator",
+            "type": "pubkey"
           },
           {
-            "name": "description",
-            "type": "string"
+            "name": "delegate",
+            "type": "pubkey"
           },
           {
             "name": "timestamp",
@@ -1360,13 +11318,21 @@ export type DecentralizedAgSupply = {
       }
     },
     {
-      "name": "vault",
+      "name": "votesUndelegated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "delegator",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }